@@ -0,0 +1,145 @@
+use std::collections::{BTreeMap, HashMap};
+#[cfg(feature = "json")]
+use std::io::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    generation_error, BorderEntry, ConfigurableAttributes, Directions, EdgePosition, ElementIDT,
+    ErrorSubsystem, ManycoreError, ManycoreSystem, SinkSourceDirection, TaskIDT, WithID,
+};
+
+/// Per-core fields that are not part of the XML representation.
+#[derive(Serialize, Deserialize)]
+struct CoreDerivedFields {
+    router_id: ElementIDT,
+    source_loads: Option<BTreeMap<Directions, u16>>,
+    matrix_edge: Option<EdgePosition>,
+    channel_loads: BTreeMap<Directions, u16>,
+}
+
+/// Fields that are computed on parse or routing rather than read from the XML.
+#[derive(Serialize, Deserialize)]
+struct DerivedFields {
+    rows_in_id_space: ElementIDT,
+    columns_in_id_space: ElementIDT,
+    task_core_map: BTreeMap<TaskIDT, usize>,
+    configurable_attributes: ConfigurableAttributes,
+    cores: Vec<CoreDerivedFields>,
+    core_border_map: Option<HashMap<usize, HashMap<SinkSourceDirection, BorderEntry>>>,
+}
+
+/// Serialisation side of the binary representation.
+#[derive(Serialize)]
+struct BinarySnapshotRef<'a> {
+    system: &'a ManycoreSystem,
+    derived: DerivedFields,
+}
+
+/// Deserialisation side of the binary representation.
+#[derive(Deserialize)]
+struct BinarySnapshot {
+    system: ManycoreSystem,
+    derived: DerivedFields,
+}
+
+impl ManycoreSystem {
+    /// Serialises the system into a compact binary representation (MessagePack), suitable
+    /// for caching. Unlike the XML output, fields computed on parse or routing are included.
+    ///
+    /// MessagePack is used rather than a non self-describing format such as bincode
+    /// because the latter cannot represent the flattened `other_attributes` maps.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ManycoreError> {
+        rmp_serde::to_vec_named(&self.snapshot_ref())
+            .map_err(|e| generation_error(ErrorSubsystem::System, e.to_string()))
+    }
+
+    /// Serialises the system as JSON straight into the given writer, without buffering it.
+    /// The output is the same as [`ManycoreSystem::to_json`]'s.
+    #[cfg(feature = "json")]
+    pub fn write_json<W: Write>(&self, writer: W) -> Result<(), ManycoreError> {
+        serde_json::to_writer(writer, self)
+            .map_err(|e| generation_error(ErrorSubsystem::System, e.to_string()))
+    }
+
+    /// Pairs the system with its derived fields, ready to be serialised.
+    fn snapshot_ref(&self) -> BinarySnapshotRef<'_> {
+        let derived = DerivedFields {
+            rows_in_id_space: self.rows_in_id_space,
+            columns_in_id_space: self.columns_in_id_space,
+            task_core_map: self.task_core_map.clone(),
+            configurable_attributes: self.configurable_attributes.clone(),
+            cores: self
+                .cores
+                .list()
+                .iter()
+                .map(|core| CoreDerivedFields {
+                    router_id: *core.router().id(),
+                    source_loads: core.source_loads().clone(),
+                    matrix_edge: *core.matrix_edge(),
+                    channel_loads: core
+                        .channels()
+                        .channel()
+                        .iter()
+                        .map(|(direction, channel)| (*direction, *channel.current_load()))
+                        .collect(),
+                })
+                .collect(),
+            core_border_map: self
+                .borders
+                .as_ref()
+                .map(|borders| borders.core_border_map().clone()),
+        };
+
+        BinarySnapshotRef {
+            system: self,
+            derived,
+        }
+    }
+
+    /// Restores a system produced by [`ManycoreSystem::to_bytes`]. No validation or
+    /// normalisation is performed, the system is restored as it was serialised.
+    pub fn from_bytes(bytes: &[u8]) -> Result<ManycoreSystem, ManycoreError> {
+        let BinarySnapshot {
+            mut system,
+            derived,
+        } = rmp_serde::from_slice(bytes)
+            .map_err(|e| generation_error(ErrorSubsystem::System, e.to_string()))?;
+
+        if derived.cores.len() != system.cores.list().len() {
+            return Err(generation_error(
+                ErrorSubsystem::System,
+                format!(
+                    "Binary data is inconsistent: expected derived data for {} cores, found {}.",
+                    system.cores.list().len(),
+                    derived.cores.len()
+                ),
+            ));
+        }
+
+        system.rows_in_id_space = derived.rows_in_id_space;
+        system.columns_in_id_space = derived.columns_in_id_space;
+        system.task_core_map = derived.task_core_map;
+        system.configurable_attributes = derived.configurable_attributes;
+
+        for (core, core_derived) in system.cores.list_mut().iter_mut().zip(derived.cores) {
+            core.router_mut().set_id(core_derived.router_id);
+            core.set_source_loads(core_derived.source_loads);
+            core.set_matrix_edge(core_derived.matrix_edge);
+
+            for (direction, load) in core_derived.channel_loads {
+                if let Some(channel) = core.channels_mut().channel_mut().get_mut(&direction) {
+                    channel.set_current_load(load);
+                }
+            }
+        }
+
+        if let (Some(borders), Some(core_border_map)) =
+            (system.borders.as_mut(), derived.core_border_map)
+        {
+            borders.set_core_border_map(core_border_map);
+        }
+
+        Ok(system)
+    }
+}
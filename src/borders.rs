@@ -1,10 +1,12 @@
 use std::collections::{BTreeMap, HashMap};
 
-use getset::{Getters, MutGetters};
-use manycore_utils::{deserialize_btree_vector, serialise_btreemap_and_sort};
+use getset::{Getters, MutGetters, Setters};
+use manycore_utils::serialise_btreemap_and_sort;
 use serde::{Deserialize, Serialize};
 
-use crate::Directions;
+use crate::{
+    generation_error, utils, Directions, ErrorSubsystem, ManycoreError, ManycoreSystem, TaskIDT,
+};
 
 pub use self::sink::Sink;
 pub use self::source::Source;
@@ -21,11 +23,33 @@ pub enum SinkSourceDirection {
     West,
 }
 
+impl SinkSourceDirection {
+    /// Returns all four directions, in North, South, East, West order.
+    pub const fn all() -> [SinkSourceDirection; 4] {
+        [
+            SinkSourceDirection::North,
+            SinkSourceDirection::South,
+            SinkSourceDirection::East,
+            SinkSourceDirection::West,
+        ]
+    }
+
+    /// Returns the direction opposite to the provided one (North <-> South, East <-> West).
+    pub fn opposite(&self) -> SinkSourceDirection {
+        match self {
+            SinkSourceDirection::North => SinkSourceDirection::South,
+            SinkSourceDirection::South => SinkSourceDirection::North,
+            SinkSourceDirection::West => SinkSourceDirection::East,
+            SinkSourceDirection::East => SinkSourceDirection::West,
+        }
+    }
+}
+
 /// Enum to differentiate an entry in [`Borders`]' core_border_map`.
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub enum BorderEntry {
-    Source(u16),
-    Sink(u16),
+    Source(TaskIDT),
+    Sink(TaskIDT),
 }
 
 #[cfg(doc)]
@@ -38,26 +62,26 @@ pub(crate) trait BorderRouter {
 }
 
 /// Object representation of `<Borders>` as provided in XML input file.
-#[derive(Serialize, Deserialize, Debug, PartialEq, Getters, MutGetters)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Getters, MutGetters, Setters, Clone)]
 #[getset(get = "pub", get_mut = "pub")]
 pub struct Borders {
     #[serde(
         rename = "Source",
         skip_serializing_if = "BTreeMap::is_empty",
         serialize_with = "serialise_btreemap_and_sort",
-        deserialize_with = "deserialize_btree_vector"
+        deserialize_with = "utils::borders::deserialize_sources"
     )]
-    sources: BTreeMap<u16, Source>,
+    sources: BTreeMap<TaskIDT, Source>,
     #[serde(
         rename = "Sink",
         skip_serializing_if = "BTreeMap::is_empty",
         serialize_with = "serialise_btreemap_and_sort",
-        deserialize_with = "deserialize_btree_vector"
+        deserialize_with = "utils::borders::deserialize_sinks"
     )]
-    sinks: BTreeMap<u16, Sink>,
+    sinks: BTreeMap<TaskIDT, Sink>,
     /// A map to retrieve border elements connected to a certain [`Core`].
     #[serde(skip)]
-    #[getset(get = "pub")]
+    #[getset(get = "pub", set = "pub(crate)")]
     core_border_map: HashMap<usize, HashMap<SinkSourceDirection, BorderEntry>>,
 }
 
@@ -65,8 +89,8 @@ impl Borders {
     #[cfg(test)]
     /// Creates a new instance of [`Borders`] according to the prrovided parameters.
     pub(crate) fn new(
-        sinks: BTreeMap<u16, Sink>,
-        sources: BTreeMap<u16, Source>,
+        sinks: BTreeMap<TaskIDT, Sink>,
+        sources: BTreeMap<TaskIDT, Source>,
         core_border_map: HashMap<usize, HashMap<SinkSourceDirection, BorderEntry>>,
     ) -> Self {
         Self {
@@ -76,6 +100,125 @@ impl Borders {
         }
     }
 
+    /// Returns the number of sources.
+    pub fn source_count(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// Returns the number of sinks.
+    pub fn sink_count(&self) -> usize {
+        self.sinks.len()
+    }
+
+    /// Attaches a new [`Source`] for the given task to the given core, inferring its direction
+    /// from the core's position on the matrix edge. Fails if the core is not on the edge or if
+    /// more than one direction is possible (i.e. corners), if that side of the core already has
+    /// a border router, as well as if the task is already allocated on a core or border router.
+    pub fn add_source_auto(
+        &mut self,
+        core_id: usize,
+        task_id: TaskIDT,
+        system: &ManycoreSystem,
+    ) -> Result<SinkSourceDirection, ManycoreError> {
+        if self.sources.contains_key(&task_id) || self.sinks.contains_key(&task_id) {
+            return Err(generation_error(
+                ErrorSubsystem::Borders,
+                format!("Task {task_id} is already allocated on a border router."),
+            ));
+        }
+
+        if let Some(core) = system.task_core_map().get(&task_id) {
+            return Err(generation_error(
+                ErrorSubsystem::Borders,
+                format!("Task {task_id} is already allocated on core {core}."),
+            ));
+        }
+
+        let core = system.cores().list().get(core_id).ok_or(generation_error(
+            ErrorSubsystem::Borders,
+            format!("Could not get a core with ID {core_id}."),
+        ))?;
+
+        let edge_position = core.matrix_edge().as_ref().ok_or(generation_error(
+            ErrorSubsystem::Borders,
+            format!(
+                "Core {core_id} is not on the matrix edge and cannot be connected to a Source."
+            ),
+        ))?;
+
+        let direction = match Vec::<SinkSourceDirection>::from(edge_position).as_slice() {
+            [direction] => *direction,
+            _ => {
+                return Err(generation_error(ErrorSubsystem::Borders, format!(
+                    "Core {core_id} has multiple outward directions. Please specify the Source direction manually."
+                )))
+            }
+        };
+
+        if self
+            .core_border_map
+            .get(&core_id)
+            .is_some_and(|entries| entries.contains_key(&direction))
+        {
+            return Err(generation_error(
+                ErrorSubsystem::Borders,
+                format!("Core {core_id} already has a border router on its {direction:?} side."),
+            ));
+        }
+
+        self.sources
+            .insert(task_id, Source::new(core_id, direction, task_id, None));
+        self.core_border_map
+            .entry(core_id)
+            .or_default()
+            .insert(direction, BorderEntry::Source(task_id));
+
+        Ok(direction)
+    }
+
+    /// Ensures no task is allocated on more than one host, be it a core, a [`Source`] or a [`Sink`].
+    pub(crate) fn validate_allocations(
+        &self,
+        task_core_map: &BTreeMap<TaskIDT, usize>,
+    ) -> Result<(), ManycoreError> {
+        let conflict = |task_id: &TaskIDT, first: String, second: String| {
+            generation_error(
+                ErrorSubsystem::Borders,
+                format!("Task {task_id} is allocated on both {first} and {second}."),
+            )
+        };
+
+        for (task_id, source) in &self.sources {
+            if let Some(core) = task_core_map.get(task_id) {
+                return Err(conflict(
+                    task_id,
+                    format!("core {core}"),
+                    format!("the source connected to core {}", source.core_id()),
+                ));
+            }
+        }
+
+        for (task_id, sink) in &self.sinks {
+            if let Some(core) = task_core_map.get(task_id) {
+                return Err(conflict(
+                    task_id,
+                    format!("core {core}"),
+                    format!("the sink connected to core {}", sink.core_id()),
+                ));
+            }
+
+            if let Some(source) = self.sources.get(task_id) {
+                return Err(conflict(
+                    task_id,
+                    format!("the source connected to core {}", source.core_id()),
+                    format!("the sink connected to core {}", sink.core_id()),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Populates the `core_border_map` by inspecting each [`Source`] and [`Sink`] within a [`Borders`] instance.
     pub(crate) fn compute_core_border_map(&mut self) {
         for source in self.sources.values() {
@@ -94,6 +237,35 @@ impl Borders {
     }
 }
 
+impl ManycoreSystem {
+    /// Returns the number of (sources, sinks) in the system, (0, 0) if it has no borders.
+    pub fn border_counts(&self) -> (usize, usize) {
+        self.borders.as_ref().map_or((0, 0), |borders| {
+            (borders.source_count(), borders.sink_count())
+        })
+    }
+
+    /// Resolves a task allocated on a border router to the (core id, direction) the border
+    /// router is connected to, along with whether it is a [`Sink`] (`true`) or a [`Source`].
+    /// Returns [`None`] if the task is not allocated on a border router.
+    pub fn border_of_task(&self, task_id: TaskIDT) -> Option<(usize, SinkSourceDirection, bool)> {
+        let borders = self.borders.as_ref()?;
+        let resolve =
+            |border: &dyn BorderRouter, is_sink| (*border.core_id(), *border.direction(), is_sink);
+
+        borders
+            .sinks
+            .get(&task_id)
+            .map(|sink| resolve(sink, true))
+            .or_else(|| {
+                borders
+                    .sources
+                    .get(&task_id)
+                    .map(|source| resolve(source, false))
+            })
+    }
+}
+
 impl From<&SinkSourceDirection> for Directions {
     fn from(value: &SinkSourceDirection) -> Self {
         match value {
@@ -1,25 +1,29 @@
-use getset::Getters;
+use getset::{Getters, MutGetters};
 use manycore_utils::BTreeVector;
 use serde::{Deserialize, Serialize};
 
-use crate::BorderRouter;
+use crate::{utils, BorderRouter, TaskIDT};
 
 use super::SinkSourceDirection;
 
 /// Object representation of a `<Sink>` element as provided in XML input file.
-#[derive(Serialize, Deserialize, Getters, Debug, PartialEq, Clone, Eq)]
+#[derive(Serialize, Deserialize, Getters, Debug, PartialEq, Clone, Eq, MutGetters)]
 #[getset(get = "pub")]
 pub struct Sink {
     #[serde(rename = "@coreID")]
+    #[getset(get = "pub", get_mut = "pub(crate)")]
     core_id: usize,
     #[serde(rename = "@direction")]
     direction: SinkSourceDirection,
-    #[serde(rename = "@taskid")]
-    task_id: u16,
+    #[serde(
+        rename = "@taskid",
+        deserialize_with = "utils::task_id::deserialize_task_id"
+    )]
+    task_id: TaskIDT,
 }
 
-impl BTreeVector<u16> for Sink {
-    fn key(&self) -> u16 {
+impl BTreeVector<TaskIDT> for Sink {
+    fn key(&self) -> TaskIDT {
         self.task_id
     }
 }
@@ -39,7 +43,7 @@ impl PartialOrd for Sink {
 #[cfg(test)]
 impl Sink {
     /// Generates a new [`Sink`] instance accorrding to provided parameters.
-    pub(crate) fn new(core_id: usize, direction: SinkSourceDirection, task_id: u16) -> Self {
+    pub(crate) fn new(core_id: usize, direction: SinkSourceDirection, task_id: TaskIDT) -> Self {
         Self {
             core_id,
             direction,
@@ -56,4 +60,4 @@ impl BorderRouter for Sink {
     fn direction(&self) -> &SinkSourceDirection {
         Sink::direction(&self)
     }
-}
\ No newline at end of file
+}
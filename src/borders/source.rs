@@ -2,7 +2,7 @@ use getset::{Getters, MutGetters};
 use manycore_utils::BTreeVector;
 use serde::{Deserialize, Serialize};
 
-use crate::BorderRouter;
+use crate::{utils, BorderRouter, TaskIDT};
 
 use super::SinkSourceDirection;
 
@@ -11,18 +11,22 @@ use super::SinkSourceDirection;
 #[getset(get = "pub")]
 pub struct Source {
     #[serde(rename = "@coreID")]
+    #[getset(get = "pub", get_mut = "pub(crate)")]
     core_id: usize,
     #[serde(rename = "@direction")]
     direction: SinkSourceDirection,
-    #[serde(rename = "@taskid")]
-    task_id: u16,
+    #[serde(
+        rename = "@taskid",
+        deserialize_with = "utils::task_id::deserialize_task_id"
+    )]
+    task_id: TaskIDT,
     #[serde(rename = "@actualComCost", skip_serializing_if = "Option::is_none")]
     #[getset(get = "pub")]
     actual_com_cost: Option<u16>,
 }
 
-impl BTreeVector<u16> for Source {
-    fn key(&self) -> u16 {
+impl BTreeVector<TaskIDT> for Source {
+    fn key(&self) -> TaskIDT {
         self.task_id
     }
 }
@@ -39,13 +43,12 @@ impl PartialOrd for Source {
     }
 }
 
-#[cfg(test)]
 impl Source {
     /// Generates a new [`Source`] instance according to provided parameters.
     pub(crate) fn new(
         core_id: usize,
         direction: SinkSourceDirection,
-        task_id: u16,
+        task_id: TaskIDT,
         actual_com_cost: Option<u16>,
     ) -> Self {
         Self {
@@ -1,12 +1,17 @@
 use std::{collections::BTreeMap, fmt::Display};
 
-use getset::{Getters, MutGetters};
+use getset::{Getters, MutGetters, Setters};
 use manycore_utils::{deserialize_btree_vector, serialise_btreemap, BTreeVector};
 use serde::{Deserialize, Serialize};
 
 use crate::error::ManycoreError;
 use crate::utils::attrs::deserialize_attrs;
-use crate::{ManycoreErrorKind, WithXMLAttributes};
+use crate::{
+    generation_error, ErrorSubsystem, ManycoreErrorKind, WithXMLAttributes, CURRENT_LOAD_KEY,
+};
+
+#[cfg(doc)]
+use crate::RoutingAlgorithms;
 
 static NORTH: &str = "North";
 static SOUTH: &str = "South";
@@ -22,6 +27,28 @@ pub enum Directions {
     East,
 }
 
+impl Directions {
+    /// Returns all four directions, in North, South, East, West order.
+    pub const fn all() -> [Directions; 4] {
+        [
+            Directions::North,
+            Directions::South,
+            Directions::East,
+            Directions::West,
+        ]
+    }
+
+    /// Returns the direction opposite to the provided one (North <-> South, East <-> West).
+    pub fn opposite(&self) -> Directions {
+        match self {
+            Directions::North => Directions::South,
+            Directions::South => Directions::North,
+            Directions::West => Directions::East,
+            Directions::East => Directions::West,
+        }
+    }
+}
+
 impl Display for Directions {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", String::from(self))
@@ -42,13 +69,16 @@ impl From<&Directions> for String {
 impl TryFrom<&str> for Directions {
     type Error = ManycoreError;
 
+    /// Parses a direction from its name, ignoring case. Single letter
+    /// abbreviations (e.g. "n" for North) are accepted too.
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
-            n if n == NORTH => Ok(Directions::North),
-            s if s == SOUTH => Ok(Directions::South),
-            w if w == WEST => Ok(Directions::West),
-            e if e == EAST => Ok(Directions::East),
+        match value.to_ascii_lowercase().as_str() {
+            "n" | "north" => Ok(Directions::North),
+            "s" | "south" => Ok(Directions::South),
+            "w" | "west" => Ok(Directions::West),
+            "e" | "east" => Ok(Directions::East),
             _ => Err(ManycoreError::new(ManycoreErrorKind::GenerationError(
+                ErrorSubsystem::Cores,
                 format!("'{value}' is not a valid direction."),
             ))),
         }
@@ -56,21 +86,27 @@ impl TryFrom<&str> for Directions {
 }
 
 /// Object representation of a `<Channel>` element as provided in XML input.
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Getters)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Getters, Setters)]
 pub struct Channel {
     /// The channel's direction.
     #[serde(rename = "@direction")]
+    #[getset(get = "pub")]
     direction: Directions,
     /// The channel's bandwidth.
     #[serde(rename = "@bandwidth")]
     #[getset(get = "pub")]
     bandwidth: u16,
+    /// The channel's observed load, used by [`RoutingAlgorithms::Observed`].
     #[serde(rename = "@actualComCost")]
-    #[getset(get = "pub")]
+    #[getset(get = "pub", set = "pub")]
     actual_com_cost: u16,
+    /// The channel's latency, if annotated.
+    #[serde(rename = "@latency", default, skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub")]
+    latency: Option<u16>,
     /// The load on the channel
     #[serde(skip)]
-    #[getset(get = "pub")]
+    #[getset(get = "pub", set = "pub(crate)")]
     current_load: u16,
     /// Any other channel attribute present in the XML.
     #[serde(
@@ -95,14 +131,45 @@ impl Channel {
             direction,
             actual_com_cost,
             bandwidth,
+            latency: None,
             other_attributes,
             current_load: 0,
         }
     }
 
-    /// Adds to the current load of a [`Channel`].
+    /// Mutable access to the channel's other attributes.
+    pub(crate) fn other_attributes_mut(&mut self) -> &mut Option<BTreeMap<String, String>> {
+        &mut self.other_attributes
+    }
+
+    /// Moves a `@currentLoad` attribute, if any, out of the other attributes and into the current load.
+    pub(crate) fn adopt_current_load(&mut self) -> Result<(), ManycoreError> {
+        let Some(attributes) = self.other_attributes.as_mut() else {
+            return Ok(());
+        };
+
+        if let Some(value) = attributes.remove(CURRENT_LOAD_KEY) {
+            self.current_load = value.parse::<u16>().map_err(|_| {
+                generation_error(
+                    ErrorSubsystem::Cores,
+                    format!(
+                        "Channel {} has {CURRENT_LOAD_KEY} = {value}, which is not a valid load.",
+                        self.direction
+                    ),
+                )
+            })?;
+
+            if attributes.is_empty() {
+                self.other_attributes = None;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds to the current load of a [`Channel`], saturating at [`u16::MAX`].
     pub(crate) fn add_to_load(&mut self, cost: u16) {
-        self.current_load += cost;
+        self.current_load = self.current_load.saturating_add(cost);
     }
 }
 
@@ -123,7 +190,7 @@ impl WithXMLAttributes for Channel {
 }
 
 /// Object representation of a `<Channels>` element as provided in XML input.
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Getters, MutGetters)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Getters, MutGetters, Default)]
 pub struct Channels {
     /// A map of channels that uses direction as key and the [`Channel`] itself as value.
     #[serde(
@@ -142,6 +209,35 @@ impl Channels {
         Self { channel }
     }
 
+    /// Instantiates a [`Channels`] instance with a [`Channel`] in every direction.
+    /// Each channel has the given bandwidth, no observed cost and no other attributes.
+    pub fn full_mesh(bandwidth: u16) -> Self {
+        let channel = Directions::all()
+            .into_iter()
+            .map(|direction| {
+                (
+                    direction,
+                    Channel {
+                        direction,
+                        bandwidth,
+                        actual_com_cost: 0,
+                        latency: None,
+                        current_load: 0,
+                        other_attributes: None,
+                    },
+                )
+            })
+            .collect();
+
+        Self { channel }
+    }
+
+    /// Returns the channels in a fixed direction order, as defined by [`Directions`]' ordering
+    /// (North, South, West, East). Missing directions are skipped.
+    pub fn ordered(&self) -> Vec<&Channel> {
+        self.channel.values().collect()
+    }
+
     /// Clears all [`Channel`] loads within the provided [`Channels`] instance.
     pub(crate) fn clear_loads(&mut self) {
         self.channel
@@ -150,11 +246,7 @@ impl Channels {
     }
 
     /// Adds to the [`Channel`]'s load in the given [`Directions`] within the provided [`Channels`] instance.
-    pub(crate) fn add_to_load(
-        &mut self,
-        cost: u16,
-        direction: Directions,
-    ) -> Result<(), ManycoreError> {
+    pub fn add_to_load(&mut self, cost: u16, direction: Directions) -> Result<(), ManycoreError> {
         self.channel
             .get_mut(&direction)
             .ok_or(ManycoreError::new(
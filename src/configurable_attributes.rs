@@ -1,9 +1,9 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use getset::Getters;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-use crate::{RoutingAlgorithms, ROUTING_KEY};
+use crate::{ManycoreSystem, RoutingAlgorithms, ROUTING_KEY};
 
 #[cfg(doc)]
 use crate::{Channel, Core, Router};
@@ -22,6 +22,14 @@ pub trait WithXMLAttributes {
     fn variant(&self) -> &'static str;
 }
 
+/// The kinds of element that implement [`WithXMLAttributes`].
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ElementKind {
+    Core,
+    Router,
+    Channel,
+}
+
 /// Similarly to [`WithXMLAttributes`], this trait is used to provide an abstraction
 /// over objects that have an id field.
 pub trait WithID<T> {
@@ -30,7 +38,7 @@ pub trait WithID<T> {
 
 /// Enum to differentiate what kind of attribute this is. Used in SVG customisation.
 /// It is here rather than in `manycore_svg` as the type is determined on the `manycore_parser` side.
-#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
 pub enum AttributeType {
     Text,
@@ -40,8 +48,18 @@ pub enum AttributeType {
     Routing,
 }
 
+/// Infers the [`AttributeType`] of an XML attribute value, as done when generating
+/// [`ConfigurableAttributes`]. Values that parse as an unsigned integer are
+/// [`AttributeType::Number`], anything else (floats and negatives included) is [`AttributeType::Text`].
+pub fn classify_attribute(value: &str) -> AttributeType {
+    match value.parse::<u64>() {
+        Ok(_) => AttributeType::Number,
+        Err(_) => AttributeType::Text,
+    }
+}
+
 /// Helper struct to provide a user friendly string of an attribute (`display` field )and its type (`_type` field).
-#[derive(Serialize, PartialEq, Debug, Clone)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct ProcessedAttribute {
     #[serde(rename = "type")]
     _type: AttributeType,
@@ -122,13 +140,19 @@ impl ProcessedAttribute {
             display: Self::format_display(key),
         }
     }
+
+    /// Returns the attribute's type.
+    pub fn attribute_type(&self) -> &AttributeType {
+        &self._type
+    }
 }
 
 /// A struct containing information about what customisation
 /// parameters to provide the user with.
 /// This will be serialised as JSON
-#[derive(Serialize, Getters, Default, PartialEq, Debug, Clone)]
+#[derive(Serialize, Deserialize, Getters, Default, PartialEq, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
 pub struct ConfigurableAttributes {
     core: BTreeMap<String, ProcessedAttribute>,
     router: BTreeMap<String, ProcessedAttribute>,
@@ -154,6 +178,16 @@ impl ConfigurableAttributes {
             channel,
         }
     }
+
+    /// Mutable access to the core, router and channel attributes maps, in this order.
+    pub(crate) fn maps_mut(&mut self) -> [&mut BTreeMap<String, ProcessedAttribute>; 3] {
+        [&mut self.core, &mut self.router, &mut self.channel]
+    }
+
+    /// Registers any of the element's attributes missing from the channel attributes map.
+    pub(crate) fn extend_channel<T: WithXMLAttributes>(&mut self, element: &T) {
+        self.channel.extend_from_element(element);
+    }
 }
 
 /// A trait for convenient utilities used in [`ConfigurableAttributes`] generation.
@@ -182,16 +216,43 @@ impl AttributesMap for BTreeMap<String, ProcessedAttribute> {
             for (key, value) in other_attributes {
                 // It's worth inspecting the attribute only if missing in the map.
                 if !self.contains_key(key) {
-                    // If parsing the attribute value as a number fails, the attribute must
-                    // be a string.
-                    let processed_attribute = match value.parse::<u64>() {
-                        Ok(_) => ProcessedAttribute::new(key, AttributeType::Number),
-                        Err(_) => ProcessedAttribute::new(key, AttributeType::Text),
-                    };
-
-                    self.insert(key.clone(), processed_attribute);
+                    self.insert(
+                        key.clone(),
+                        ProcessedAttribute::new(key, classify_attribute(value)),
+                    );
                 }
             }
         }
     }
 }
+
+impl ManycoreSystem {
+    /// Returns the distinct raw values of the given attribute (e.g. `@status`) across
+    /// all elements of the given kind. Elements missing the attribute are ignored.
+    pub fn distinct_attribute_values(&self, element: ElementKind, key: &str) -> BTreeSet<String> {
+        let mut values = BTreeSet::new();
+        let mut collect = |element: &dyn WithXMLAttributes| {
+            if let Some(value) = element
+                .other_attributes()
+                .as_ref()
+                .and_then(|attributes| attributes.get(key))
+            {
+                values.insert(value.clone());
+            }
+        };
+
+        for core in self.cores().list() {
+            match element {
+                ElementKind::Core => collect(core),
+                ElementKind::Router => collect(core.router()),
+                ElementKind::Channel => core
+                    .channels()
+                    .channel()
+                    .values()
+                    .for_each(|channel| collect(channel)),
+            }
+        }
+
+        values
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     channels::Channels, router::*, routing_error, utils, Directions, ElementIDT, ManycoreError,
-    SinkSourceDirection, WithID, WithXMLAttributes,
+    ManycoreSystem, SinkSourceDirection, TaskIDT, WithID, WithXMLAttributes, POWER_KEY,
+    TASK_COST_KEY,
 };
 use getset::{Getters, MutGetters, Setters};
 use serde::{Deserialize, Serialize};
@@ -14,7 +15,7 @@ use crate::SystemDimensionsT;
 
 /// Describes where in the matrix edge the core is located.
 /// Used to determine number of edge connections.
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum EdgePosition {
     Top,
     TopLeft,
@@ -24,6 +25,16 @@ pub enum EdgePosition {
     Bottom,
     BottomLeft,
     BottomRight,
+    /// Cores of a linear array (single row) face both the top and bottom edges.
+    TopBottom,
+    TopBottomLeft,
+    TopBottomRight,
+    /// Cores of a single column face both the left and right edges.
+    LeftRight,
+    TopLeftRight,
+    BottomLeftRight,
+    /// The only core of a 1x1 system faces every edge.
+    TopBottomLeftRight,
 }
 
 impl From<&EdgePosition> for Vec<SinkSourceDirection> {
@@ -38,6 +49,13 @@ impl From<&EdgePosition> for Vec<SinkSourceDirection> {
             EdgePosition::Top => vec![North],
             EdgePosition::TopLeft => vec![North, West],
             EdgePosition::TopRight => vec![North, East],
+            EdgePosition::TopBottom => vec![North, South],
+            EdgePosition::TopBottomLeft => vec![North, South, West],
+            EdgePosition::TopBottomRight => vec![North, South, East],
+            EdgePosition::LeftRight => vec![West, East],
+            EdgePosition::TopLeftRight => vec![North, West, East],
+            EdgePosition::BottomLeftRight => vec![South, West, East],
+            EdgePosition::TopBottomLeftRight => vec![North, South, West, East],
         }
     }
 }
@@ -77,6 +95,40 @@ impl From<&EdgePosition> for BTreeSet<&Directions> {
                 ret.insert(&North);
                 ret.insert(&East);
             }
+            EdgePosition::TopBottom => {
+                ret.insert(&North);
+                ret.insert(&South);
+            }
+            EdgePosition::TopBottomLeft => {
+                ret.insert(&North);
+                ret.insert(&South);
+                ret.insert(&West);
+            }
+            EdgePosition::TopBottomRight => {
+                ret.insert(&North);
+                ret.insert(&South);
+                ret.insert(&East);
+            }
+            EdgePosition::LeftRight => {
+                ret.insert(&West);
+                ret.insert(&East);
+            }
+            EdgePosition::TopLeftRight => {
+                ret.insert(&North);
+                ret.insert(&West);
+                ret.insert(&East);
+            }
+            EdgePosition::BottomLeftRight => {
+                ret.insert(&South);
+                ret.insert(&West);
+                ret.insert(&East);
+            }
+            EdgePosition::TopBottomLeftRight => {
+                ret.insert(&North);
+                ret.insert(&South);
+                ret.insert(&West);
+                ret.insert(&East);
+            }
         };
 
         ret
@@ -95,10 +147,16 @@ pub struct Core {
     #[serde(rename = "Router")]
     router: Router,
     /// The task allocated to the core, if any.
-    #[serde(rename = "@allocatedTask", skip_serializing_if = "Option::is_none")]
-    allocated_task: Option<u16>,
+    #[serde(
+        rename = "@allocatedTask",
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "utils::task_id::deserialize_optional_task_id"
+    )]
+    allocated_task: Option<TaskIDT>,
     /// The communication channels associated with this core.
-    #[serde(rename = "Channels")]
+    /// May be provided under the router instead, see [`Core::adopt_router_channels`].
+    #[serde(rename = "Channels", default)]
     channels: Channels,
     /// Map with core's incoming source loads.
     #[serde(skip)]
@@ -123,7 +181,7 @@ impl Core {
         columns: SystemDimensionsT,
         rows: SystemDimensionsT,
         router: Router,
-        allocated_task: Option<u16>,
+        allocated_task: Option<TaskIDT>,
         channels: Channels,
         other_attributes: Option<BTreeMap<String, String>>,
     ) -> Self {
@@ -148,7 +206,31 @@ impl Core {
         columns: ElementIDT,
         rows: ElementIDT,
     ) -> Option<EdgePosition> {
+        // A single core is on every edge.
+        if rows == 1 && columns == 1 {
+            return Some(EdgePosition::TopBottomLeftRight);
+        }
+
+        // In a linear array every core is on both the top and bottom edges.
+        if rows == 1 {
+            return match id {
+                0 => Some(EdgePosition::TopBottomLeft),
+                last if last == (columns - 1) => Some(EdgePosition::TopBottomRight),
+                _ => Some(EdgePosition::TopBottom),
+            };
+        }
+
         let bl_bound = (rows - 1) * columns;
+
+        // Likewise, in a single column every core is on both the left and right edges.
+        if columns == 1 {
+            return match id {
+                0 => Some(EdgePosition::TopLeftRight),
+                bl if bl == bl_bound => Some(EdgePosition::BottomLeftRight),
+                _ => Some(EdgePosition::LeftRight),
+            };
+        }
+
         if id % columns == 0 {
             return match id {
                 0 => Some(EdgePosition::TopLeft),
@@ -170,6 +252,12 @@ impl Core {
         None
     }
 
+    /// Updates the core id, along with its router's.
+    pub(crate) fn set_id(&mut self, id: ElementIDT) {
+        self.id = id;
+        self.router.set_id(id);
+    }
+
     /// Utility function to populate the matrix_edge field.
     pub(crate) fn populate_matrix_edge(
         &mut self,
@@ -200,6 +288,83 @@ impl Core {
         Ok(())
     }
 
+    /// Mutable access to the core's other attributes.
+    pub(crate) fn other_attributes_mut(&mut self) -> &mut Option<BTreeMap<String, String>> {
+        &mut self.other_attributes
+    }
+
+    /// Whether the core's `@taskCost` attribute is set to `true`.
+    /// Absent or unparsable values count as `false`.
+    pub fn has_task_cost(&self) -> bool {
+        self.other_attributes
+            .as_ref()
+            .and_then(|attributes| attributes.get(TASK_COST_KEY))
+            .and_then(|value| value.parse::<bool>().ok())
+            .unwrap_or(false)
+    }
+
+    /// The core's `@power` attribute in milliwatts. The value must carry a `W`, `mW` or `uW`
+    /// unit suffix (e.g. "12.5mW"). Absent, unitless or unparsable values yield [`None`].
+    pub fn power_milliwatts(&self) -> Option<f64> {
+        let value = self.other_attributes.as_ref()?.get(POWER_KEY)?.trim();
+
+        let (number, scale) = if let Some(number) = value.strip_suffix("mW") {
+            (number, 1.0)
+        } else if let Some(number) = value.strip_suffix("uW") {
+            (number, 0.001)
+        } else if let Some(number) = value.strip_suffix('W') {
+            (number, 1000.0)
+        } else {
+            return None;
+        };
+
+        number
+            .trim_end()
+            .parse::<f64>()
+            .ok()
+            .map(|number| number * scale)
+    }
+
+    /// Sum of the current loads on all of the core's outgoing channels.
+    pub fn total_outgoing_load(&self) -> u32 {
+        self.channels
+            .channel()
+            .values()
+            .map(|channel| u32::from(*channel.current_load()))
+            .sum()
+    }
+
+    /// Sum of the bandwidths of all of the core's outgoing channels.
+    pub fn total_bandwidth(&self) -> u32 {
+        self.channels
+            .channel()
+            .values()
+            .map(|channel| u32::from(*channel.bandwidth()))
+            .sum()
+    }
+
+    /// Returns the directions of channels that carried observed traffic, i.e. whose
+    /// `actual_com_cost` is non-zero in the parsed data. Unaffected by routing.
+    pub fn active_observed_directions(&self) -> BTreeSet<Directions> {
+        self.channels
+            .channel()
+            .iter()
+            .filter_map(|(direction, channel)| {
+                (*channel.actual_com_cost() != 0).then_some(*direction)
+            })
+            .collect()
+    }
+
+    /// Moves channels nested under the router, if any, to the core. Channels declared at
+    /// core level take precedence: router-level ones are discarded when both are present.
+    pub(crate) fn adopt_router_channels(&mut self) {
+        if let Some(channels) = self.router.take_channels() {
+            if self.channels.channel().is_empty() {
+                self.channels = channels;
+            }
+        }
+    }
+
     /// Utility function to clear all source loads.
     pub(crate) fn clear_source_loads(&mut self) {
         self.source_loads.take();
@@ -229,6 +394,33 @@ impl WithID<ElementIDT> for Core {
     }
 }
 
+impl ManycoreSystem {
+    /// Returns the id of the core adjacent to `core_id` in the given direction, or [`None`]
+    /// if that would be off the mesh (or if `core_id` itself is not part of the mesh).
+    pub fn neighbour(&self, core_id: ElementIDT, direction: Directions) -> Option<ElementIDT> {
+        let columns = ElementIDT::from(self.columns);
+        let rows = ElementIDT::from(self.rows);
+
+        if columns == 0 || core_id >= rows * columns {
+            return None;
+        }
+
+        let (row, column) = (core_id / columns, core_id % columns);
+
+        match direction {
+            Directions::North => (row > 0).then(|| core_id - columns),
+            Directions::South => (row + 1 < rows).then(|| core_id + columns),
+            Directions::West => (column > 0).then(|| core_id - 1),
+            Directions::East => (column + 1 < columns).then(|| core_id + 1),
+        }
+    }
+
+    /// Whether the channel leaving `core_id` in the given direction points off the mesh.
+    pub fn is_boundary_channel(&self, core_id: ElementIDT, direction: Directions) -> bool {
+        self.neighbour(core_id, direction).is_none()
+    }
+}
+
 /// Object representation of `<Cores>` attributes in input XML.
 #[derive(Serialize, Deserialize, Debug, PartialEq, Getters, Setters, MutGetters, Clone)]
 #[getset(get = "pub", set = "pub", get_mut = "pub")]
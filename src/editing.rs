@@ -0,0 +1,235 @@
+use std::collections::{BTreeMap, HashMap};
+
+use getset::Getters;
+
+use crate::{
+    generation_error, to_index, Directions, Edge, ElementIDT, ErrorSubsystem, ManycoreError,
+    ManycoreSystem, ProcessedAttribute, SystemDimensionsT, TaskIDT, WithID, COORDINATES_KEY,
+};
+
+#[cfg(doc)]
+use crate::{ConfigurableAttributes, Cores};
+
+/// What [`ManycoreSystem::recompact_ids`] dropped along with the removed cores.
+#[derive(Debug, PartialEq, Clone, Getters)]
+#[getset(get = "pub")]
+pub struct RecompactReport {
+    /// Tasks no longer allocated on any core or border router, in ascending order.
+    dropped_tasks: Vec<TaskIDT>,
+    /// Task graph edges touching a dropped task, in task graph order.
+    dropped_edges: Vec<Edge>,
+}
+
+impl ManycoreSystem {
+    /// Applies `f` to the attributes map of every core, router and channel. `f` returns whether
+    /// it changed the map. Maps left empty are dropped.
+    /// Returns whether any core, router and channel map changed, in the same order as
+    /// [`ConfigurableAttributes::maps_mut`].
+    fn edit_attribute_maps<F>(&mut self, mut f: F) -> [bool; 3]
+    where
+        F: FnMut(&mut BTreeMap<String, String>) -> bool,
+    {
+        let mut edit = |attributes: &mut Option<BTreeMap<String, String>>| {
+            let Some(map) = attributes else {
+                return false;
+            };
+
+            let changed = f(map);
+            if map.is_empty() {
+                *attributes = None;
+            }
+
+            changed
+        };
+
+        let mut changed = [false; 3];
+        for core in self.cores.list_mut() {
+            changed[0] |= edit(core.other_attributes_mut());
+            changed[1] |= edit(core.router_mut().other_attributes_mut());
+
+            for channel in core.channels_mut().channel_mut().values_mut() {
+                changed[2] |= edit(channel.other_attributes_mut());
+            }
+        }
+
+        changed
+    }
+
+    /// Removes the given attributes from all cores, routers and channels, e.g. to anonymise
+    /// a system before sharing it. Removed attributes are no longer configurable.
+    /// Keys must include the `@` prefix.
+    pub fn strip_attributes(&mut self, keys: &[&str]) {
+        for key in keys {
+            let changed = self.edit_attribute_maps(|attributes| attributes.remove(*key).is_some());
+
+            for (map, changed) in self
+                .configurable_attributes
+                .maps_mut()
+                .into_iter()
+                .zip(changed)
+            {
+                if changed {
+                    map.remove(*key);
+                }
+            }
+        }
+    }
+
+    /// Renames an attribute on all cores, routers and channels, overwriting any existing
+    /// attribute named `to`. The configurable attributes are updated accordingly.
+    /// Keys must include the `@` prefix.
+    pub fn rename_attribute(&mut self, from: &str, to: &str) {
+        let changed = self.edit_attribute_maps(|attributes| match attributes.remove(from) {
+            Some(value) => {
+                attributes.insert(to.to_string(), value);
+                true
+            }
+            None => false,
+        });
+
+        for (map, changed) in self
+            .configurable_attributes
+            .maps_mut()
+            .into_iter()
+            .zip(changed)
+        {
+            if let (true, Some(attribute)) = (changed, map.remove(from)) {
+                let key = to.to_string();
+                let processed_attribute =
+                    ProcessedAttribute::new(&key, *attribute.attribute_type());
+
+                map.insert(key, processed_attribute);
+            }
+        }
+    }
+
+    /// Sets an attribute on the channel of the given core in the given direction,
+    /// e.g. to reflect live telemetry without re-parsing.
+    /// Keys not previously seen are registered in the configurable attributes.
+    pub fn update_channel_attribute(
+        &mut self,
+        core_id: ElementIDT,
+        direction: Directions,
+        key: &str,
+        value: String,
+    ) -> Result<(), ManycoreError> {
+        let ManycoreSystem {
+            ref mut cores,
+            ref mut configurable_attributes,
+            ..
+        } = *self;
+
+        let channel = cores
+            .list_mut()
+            .get_mut(usize::from(core_id))
+            .ok_or(generation_error(
+                ErrorSubsystem::Cores,
+                format!("Could not get a core with ID {core_id}."),
+            ))?
+            .channels_mut()
+            .channel_mut()
+            .get_mut(&direction)
+            .ok_or(generation_error(
+                ErrorSubsystem::Cores,
+                format!("Core {core_id} has no {direction} channel."),
+            ))?;
+
+        channel
+            .other_attributes_mut()
+            .get_or_insert(BTreeMap::new())
+            .insert(key.to_string(), value);
+
+        configurable_attributes.extend_channel(channel);
+
+        Ok(())
+    }
+
+    /// Renumbers cores to 0..n, in their current order, e.g. after some were removed from
+    /// [`Cores::list`], and lays them out on a `rows` x `columns` matrix, which must fit them
+    /// exactly. Routers, matrix edges, coordinates, the task -> core map and border routers follow
+    /// their cores. Border routers connected to removed cores are dropped, as are tasks no longer
+    /// allocated anywhere along with their task graph edges. These are reported in the return value.
+    pub fn recompact_ids(
+        &mut self,
+        rows: SystemDimensionsT,
+        columns: SystemDimensionsT,
+    ) -> Result<RecompactReport, ManycoreError> {
+        let expected_number_of_cores = to_index(rows)? * to_index(columns)?;
+        if rows == 0 || columns == 0 || self.cores.list().len() != expected_number_of_cores {
+            return Err(generation_error(
+                ErrorSubsystem::Dimensions,
+                format!(
+                    "Cannot lay {} cores out on {rows} rows and {columns} columns.",
+                    self.cores.list().len()
+                ),
+            ));
+        }
+
+        self.rows = rows;
+        self.columns = columns;
+        self.rows_in_id_space = ElementIDT::from(rows);
+        self.columns_in_id_space = ElementIDT::from(columns);
+
+        let columns = self.columns_in_id_space;
+        let rows = self.rows_in_id_space;
+
+        // Old core id -> new core id
+        let mut new_ids: BTreeMap<usize, usize> = BTreeMap::new();
+        let mut task_core_map = BTreeMap::new();
+
+        for (i, core) in self.cores.list_mut().iter_mut().enumerate() {
+            new_ids.insert(usize::from(*core.id()), i);
+
+            let id = ElementIDT::try_from(i)?;
+            core.set_id(id);
+            core.populate_matrix_edge(columns, rows);
+
+            // Coordinates, if any, must agree with the new position.
+            if let Some(coordinates) = core
+                .other_attributes_mut()
+                .as_mut()
+                .and_then(|attributes| attributes.get_mut(COORDINATES_KEY))
+            {
+                *coordinates = format!("{},{}", id % columns, id / columns);
+            }
+
+            if let Some(task_id) = core.allocated_task() {
+                task_core_map.insert(*task_id, i);
+            }
+        }
+
+        if let Some(borders) = self.borders.as_mut() {
+            borders
+                .sources_mut()
+                .retain(|_, source| new_ids.contains_key(source.core_id()));
+            borders
+                .sinks_mut()
+                .retain(|_, sink| new_ids.contains_key(sink.core_id()));
+
+            for source in borders.sources_mut().values_mut() {
+                *source.core_id_mut() = new_ids[source.core_id()];
+            }
+            for sink in borders.sinks_mut().values_mut() {
+                *sink.core_id_mut() = new_ids[sink.core_id()];
+            }
+
+            borders.set_core_border_map(HashMap::new());
+            borders.compute_core_border_map();
+        }
+
+        let borders = self.borders.as_ref();
+        let (dropped_tasks, dropped_edges) = self.task_graph.retain_tasks(|task_id| {
+            task_core_map.contains_key(task_id)
+                || borders.is_some_and(|borders| {
+                    borders.sources().contains_key(task_id) || borders.sinks().contains_key(task_id)
+                })
+        });
+
+        self.task_core_map = task_core_map;
+
+        Ok(RecompactReport {
+            dropped_tasks,
+            dropped_edges,
+        })
+    }
+}
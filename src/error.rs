@@ -3,13 +3,29 @@ use std::{error::Error, fmt::Display, num::TryFromIntError};
 #[cfg(doc)]
 use crate::ManycoreSystem;
 
+/// The part of a [`ManycoreSystem`] a [`ManycoreErrorKind::GenerationError`] originates from.
+/// Allows callers (e.g. a UI) to point the user at the relevant section of the input.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ErrorSubsystem {
+    /// The system as a whole: I/O, XML syntax, serialisation and the like.
+    System,
+    /// The declared rows and columns.
+    Dimensions,
+    /// Cores and their routers and channels.
+    Cores,
+    /// The task graph.
+    TaskGraph,
+    /// Border routers (sinks and sources).
+    Borders,
+}
+
 /// Enum to wrap possible errors that might arise when generating/updating a [`ManycoreSystem`].
 ///
 /// The string contained in each variant is a user friendly explanation of the error (or a call to `to_string()` on the error).
 #[derive(Debug)]
 pub enum ManycoreErrorKind {
     InfoError(&'static str),
-    GenerationError(String),
+    GenerationError(ErrorSubsystem, String),
     RoutingError(String),
     DimensionsConversionError(String),
 }
@@ -25,13 +41,18 @@ impl ManycoreError {
     pub fn new(error_kind: ManycoreErrorKind) -> Self {
         Self { error_kind }
     }
+
+    /// Returns the kind of error, for programmatic handling.
+    pub fn error_kind(&self) -> &ManycoreErrorKind {
+        &self.error_kind
+    }
 }
 
 impl Display for ManycoreError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self.error_kind {
             ManycoreErrorKind::InfoError(e) => write!(f, "Info Error: {}", e),
-            ManycoreErrorKind::GenerationError(e) => write!(f, "Generation Error: {}", e),
+            ManycoreErrorKind::GenerationError(_, e) => write!(f, "Generation Error: {}", e),
             ManycoreErrorKind::RoutingError(e) => write!(f, "Routing Error: {}", e),
             ManycoreErrorKind::DimensionsConversionError(e) => {
                 write!(f, "Dimensions Conversion Error: {}", e)
@@ -1,7 +1,9 @@
-use std::collections::BTreeMap;
-use manycore_utils::{deserialize_btree_vector, serialise_btreemap, BTreeVector};
 use getset::{Getters, MutGetters};
+use manycore_utils::{deserialize_btree_vector, serialise_btreemap, BTreeVector};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use crate::{generation_error, utils, ErrorSubsystem, ManycoreError, TaskIDT};
 
 /// Object representation of an `<Edge>` element in input XML.
 #[derive(Serialize, Deserialize, Debug, PartialEq, Getters, Clone)]
@@ -9,34 +11,63 @@ use serde::{Deserialize, Serialize};
 #[getset(get = "pub")]
 pub struct Edge {
     /// Edge source.
-    #[serde(rename = "@from")]
-    from: u16,
+    #[serde(
+        rename = "@from",
+        deserialize_with = "utils::task_id::deserialize_task_id"
+    )]
+    from: TaskIDT,
     /// Edge destination
-    #[serde(rename = "@to")]
-    to: u16,
+    #[serde(
+        rename = "@to",
+        deserialize_with = "utils::task_id::deserialize_task_id"
+    )]
+    to: TaskIDT,
     /// Edge cost.
     #[serde(rename = "@communicationCost")]
     communication_cost: u16,
+    /// How many times the message is sent, 1 unless specified.
+    #[serde(
+        rename = "@count",
+        default = "Edge::default_count",
+        skip_serializing_if = "Edge::is_single"
+    )]
+    count: u16,
 }
 
 impl Edge {
-    #[cfg(test)]
     /// Instantiates a new edge.
-    pub(crate) fn new(from: u16, to: u16, communication_cost: u16) -> Self {
+    pub(crate) fn new(from: TaskIDT, to: TaskIDT, communication_cost: u16) -> Self {
         Self {
             from,
             to,
             communication_cost,
+            count: Edge::default_count(),
         }
     }
+
+    fn default_count() -> u16 {
+        1
+    }
+
+    fn is_single(count: &u16) -> bool {
+        *count == 1
+    }
+
+    /// The load the edge puts on each channel it crosses: its communication cost times its count.
+    pub fn load(&self) -> u16 {
+        self.communication_cost.saturating_mul(self.count)
+    }
 }
 
 /// Object representation of a `<Task>` element in input XML.
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Getters)]
 #[getset(get = "pub")]
 pub struct Task {
-    #[serde(rename = "@id")]
-    id: u16,
+    #[serde(
+        rename = "@id",
+        deserialize_with = "utils::task_id::deserialize_task_id"
+    )]
+    id: TaskIDT,
     #[serde(rename = "@computationCost")]
     computation_cost: u8,
 }
@@ -44,7 +75,7 @@ pub struct Task {
 impl Task {
     #[cfg(test)]
     /// Instantiates a new task.
-    pub(crate) fn new(id: u16, computation_cost: u8) -> Self {
+    pub(crate) fn new(id: TaskIDT, computation_cost: u8) -> Self {
         Self {
             id,
             computation_cost,
@@ -52,8 +83,8 @@ impl Task {
     }
 }
 
-impl BTreeVector<u16> for Task {
-    fn key(&self) -> u16 {
+impl BTreeVector<TaskIDT> for Task {
+    fn key(&self) -> TaskIDT {
         self.id
     }
 }
@@ -68,7 +99,7 @@ pub struct TaskGraph {
         deserialize_with = "deserialize_btree_vector",
         serialize_with = "serialise_btreemap"
     )]
-    tasks: BTreeMap<u16, Task>,
+    tasks: BTreeMap<TaskIDT, Task>,
     /// Vector of edges connecting tasks (grpah edges).
     #[serde(rename = "Edge")]
     #[getset(get = "pub", get_mut = "pub")]
@@ -78,7 +109,268 @@ pub struct TaskGraph {
 impl TaskGraph {
     #[cfg(test)]
     /// Instantiates a new Taskgraph.
-    pub(crate) fn new(tasks: BTreeMap<u16, Task>, edges: Vec<Edge>) -> Self {
+    pub(crate) fn new(tasks: BTreeMap<TaskIDT, Task>, edges: Vec<Edge>) -> Self {
         Self { tasks, edges }
     }
+
+    /// Drops the tasks for which `keep` returns false, along with every edge touching them.
+    /// Returns the dropped task ids (edge endpoints included) and the dropped edges.
+    pub(crate) fn retain_tasks<F>(&mut self, keep: F) -> (Vec<TaskIDT>, Vec<Edge>)
+    where
+        F: Fn(&TaskIDT) -> bool,
+    {
+        let dropped_tasks = self.task_ids().into_iter().filter(|id| !keep(id)).collect();
+
+        self.tasks.retain(|id, _| keep(id));
+        let (edges, dropped_edges) = std::mem::take(&mut self.edges)
+            .into_iter()
+            .partition(|edge| keep(&edge.from) && keep(&edge.to));
+        self.edges = edges;
+
+        (dropped_tasks, dropped_edges)
+    }
+
+    /// Returns every task id referenced by the graph, including edge endpoints
+    /// that are not listed as tasks (e.g. tasks allocated on border routers).
+    fn task_ids(&self) -> BTreeSet<TaskIDT> {
+        self.tasks
+            .keys()
+            .copied()
+            .chain(self.edges.iter().flat_map(|edge| [edge.from, edge.to]))
+            .collect()
+    }
+
+    /// Checks task ids form a contiguous range starting from the smallest one.
+    /// Fails naming the first missing id, if any.
+    pub fn assert_contiguous_ids(&self) -> Result<(), ManycoreError> {
+        let mut ids = self.tasks.keys();
+
+        let Some(mut previous) = ids.next().copied() else {
+            return Ok(());
+        };
+
+        for id in ids {
+            if *id != previous + 1 {
+                return Err(generation_error(
+                    ErrorSubsystem::TaskGraph,
+                    format!(
+                        "Task IDs are not contiguous: expected task {}, found task {id} after task {previous}.",
+                        previous + 1
+                    ),
+                ));
+            }
+
+            previous = *id;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the graph's edges ordered by (from, to), regardless of file order.
+    /// Edges sharing both endpoints keep their relative file order.
+    pub fn sorted_edges(&self) -> Vec<&Edge> {
+        let mut ret = self.edges.iter().collect::<Vec<&Edge>>();
+        ret.sort_by_key(|edge| (edge.from, edge.to));
+
+        ret
+    }
+
+    /// Returns the graph's adjacency list: each task id mapped to the ids of the tasks
+    /// its outgoing edges point to, in edge order. Every task id appears as a key.
+    pub fn adjacency(&self) -> BTreeMap<TaskIDT, Vec<TaskIDT>> {
+        let mut ret: BTreeMap<TaskIDT, Vec<TaskIDT>> = self
+            .task_ids()
+            .into_iter()
+            .map(|id| (id, Vec::new()))
+            .collect();
+
+        for edge in &self.edges {
+            ret.entry(edge.from).or_default().push(edge.to);
+        }
+
+        ret
+    }
+
+    /// Returns the ids of tasks with no incoming edges (entry tasks), in ascending order.
+    pub fn roots(&self) -> Vec<TaskIDT> {
+        let destinations = self
+            .edges
+            .iter()
+            .map(|edge| edge.to)
+            .collect::<BTreeSet<TaskIDT>>();
+
+        self.task_ids()
+            .into_iter()
+            .filter(|id| !destinations.contains(id))
+            .collect()
+    }
+
+    /// Returns the ids of tasks with no outgoing edges (exit tasks), in ascending order.
+    pub fn leaves(&self) -> Vec<TaskIDT> {
+        self.adjacency()
+            .into_iter()
+            .filter_map(|(id, to)| to.is_empty().then_some(id))
+            .collect()
+    }
+
+    /// Returns the path with the fewest edges from `from` to `to` (both included), following
+    /// edge direction. Ties are broken by edge order. Returns [`None`] if `to` is unreachable.
+    pub fn shortest_path(&self, from: TaskIDT, to: TaskIDT) -> Option<Vec<TaskIDT>> {
+        let adjacency = self.adjacency();
+
+        if !adjacency.contains_key(&from) || !adjacency.contains_key(&to) {
+            return None;
+        }
+
+        // Maps each visited task to the task it was reached from.
+        let mut previous: BTreeMap<TaskIDT, Option<TaskIDT>> = BTreeMap::from([(from, None)]);
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(id) = queue.pop_front() {
+            if id == to {
+                let mut ret = vec![to];
+                while let Some(Some(prev)) = previous.get(ret.last()?) {
+                    ret.push(*prev);
+                }
+                ret.reverse();
+
+                return Some(ret);
+            }
+
+            for next in &adjacency[&id] {
+                if !previous.contains_key(next) {
+                    previous.insert(*next, Some(id));
+                    queue.push_back(*next);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Computes a topological ordering of the graph's tasks (Kahn's algorithm).
+    /// Ties are broken by ascending task id. Fails if the graph contains a cycle.
+    pub fn topological_order(&self) -> Result<Vec<TaskIDT>, ManycoreError> {
+        let adjacency = self.adjacency();
+
+        let mut in_degree: BTreeMap<TaskIDT, usize> = adjacency.keys().map(|id| (*id, 0)).collect();
+        for to in adjacency.values().flatten() {
+            *in_degree.entry(*to).or_insert(0) += 1;
+        }
+
+        // Kept sorted, so the lowest ready task id always comes next.
+        let mut ready: BTreeSet<TaskIDT> = in_degree
+            .iter()
+            .filter_map(|(id, degree)| (*degree == 0).then_some(*id))
+            .collect();
+        let mut ret = Vec::with_capacity(in_degree.len());
+
+        while let Some(id) = ready.pop_first() {
+            ret.push(id);
+
+            for to in &adjacency[&id] {
+                if let Some(degree) = in_degree.get_mut(to) {
+                    *degree -= 1;
+                    if *degree == 0 {
+                        ready.insert(*to);
+                    }
+                }
+            }
+        }
+
+        if ret.len() != in_degree.len() {
+            return Err(generation_error(
+                ErrorSubsystem::TaskGraph,
+                "Malformed TaskGraph: the graph contains a cycle.".into(),
+            ));
+        }
+
+        Ok(ret)
+    }
+
+    /// Number of tasks on the longest dependency chain (edge count + 1).
+    /// Communication and computation costs are ignored. Fails if the graph contains a cycle.
+    pub fn max_depth(&self) -> Result<u32, ManycoreError> {
+        let adjacency = self.adjacency();
+
+        // Depth of the longest chain ending at each task.
+        let mut depth: BTreeMap<TaskIDT, u32> = BTreeMap::new();
+
+        for id in self.topological_order()? {
+            let current = *depth.entry(id).or_insert(1);
+
+            for to in &adjacency[&id] {
+                let next = depth.entry(*to).or_insert(1);
+                *next = (*next).max(current + 1);
+            }
+        }
+
+        Ok(depth.into_values().max().unwrap_or(0))
+    }
+
+    /// Whether the graph is a tree: it has one edge less than it has tasks, is connected
+    /// when ignoring edge direction and contains no cycle. An empty graph is not a tree.
+    pub fn is_tree(&self) -> bool {
+        if self.edges.len() + 1 != self.task_ids().len() || self.topological_order().is_err() {
+            return false;
+        }
+
+        self.weakly_connected_components().len() == 1
+    }
+
+    /// Groups tasks into weakly connected components, i.e. ignoring edge direction.
+    /// Each component is a sorted list of task ids, components are ordered by their smallest id.
+    pub fn weakly_connected_components(&self) -> Vec<Vec<TaskIDT>> {
+        let ids = self.task_ids().into_iter().collect::<Vec<TaskIDT>>();
+        let index = |id: &TaskIDT| ids.binary_search(id).expect("Edge endpoints are task ids.");
+
+        // Union-find over task indices.
+        let mut parent = (0..ids.len()).collect::<Vec<usize>>();
+        fn find(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+
+            i
+        }
+
+        for edge in &self.edges {
+            let from = find(&mut parent, index(&edge.from));
+            let to = find(&mut parent, index(&edge.to));
+            // Keep the smallest index as root, so components come out ordered.
+            parent[from.max(to)] = from.min(to);
+        }
+
+        let mut components: BTreeMap<usize, Vec<TaskIDT>> = BTreeMap::new();
+        for (i, id) in ids.iter().enumerate() {
+            components
+                .entry(find(&mut parent, i))
+                .or_default()
+                .push(*id);
+        }
+
+        components.into_values().collect()
+    }
+
+    /// Returns (task id, in-degree, out-degree) for every task whose in-degree or
+    /// out-degree exceeds the given threshold, sorted by task id.
+    pub fn hotspots(&self, threshold: u32) -> Vec<(TaskIDT, u32, u32)> {
+        let adjacency = self.adjacency();
+
+        let mut in_degree: BTreeMap<TaskIDT, u32> = BTreeMap::new();
+        for to in adjacency.values().flatten() {
+            *in_degree.entry(*to).or_insert(0) += 1;
+        }
+
+        adjacency
+            .iter()
+            .filter_map(|(id, successors)| {
+                let fan_in = in_degree.get(id).copied().unwrap_or(0);
+                let fan_out = successors.len() as u32;
+
+                (fan_in > threshold || fan_out > threshold).then_some((*id, fan_in, fan_out))
+            })
+            .collect()
+    }
 }
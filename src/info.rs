@@ -1,25 +1,83 @@
 use std::collections::BTreeMap;
 
+use getset::Getters;
+
 use crate::{
     error::{ManycoreError, ManycoreErrorKind},
-    Core, ManycoreSystem, WithID, WithXMLAttributes, ID_KEY,
+    Core, Directions, ManycoreSystem, WithID, WithXMLAttributes, ID_KEY,
 };
 
 static TASK_KEY: &'static str = "@allocatedTask";
 
+/// A borrowed view over a core's or router's information.
+/// It references the element's `other_attributes` map rather than cloning it and
+/// only owns the fields that are computed on request (e.g. a core's id).
+#[derive(Debug, PartialEq, Getters)]
+#[getset(get = "pub")]
+pub struct InfoRef<'a> {
+    /// The element's attributes as provided in the XML, if any.
+    attributes: Option<&'a BTreeMap<String, String>>,
+    /// Fields that are not part of the element's `other_attributes` map.
+    extras: BTreeMap<&'static str, String>,
+}
+
+impl<'a> InfoRef<'a> {
+    /// Retrieves the value associated with the given key, if any.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        if let Some(value) = self.extras.get(key) {
+            return Some(value.as_str());
+        }
+
+        self.attributes
+            .and_then(|attributes| attributes.get(key))
+            .map(|value| value.as_str())
+    }
+
+    /// Iterates over all key-value pairs, attributes first and extras after.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.attributes
+            .into_iter()
+            .flat_map(|attributes| attributes.iter())
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .chain(self.extras.iter().map(|(k, v)| (*k, v.as_str())))
+    }
+}
+
+impl<'a> From<InfoRef<'a>> for Option<BTreeMap<String, String>> {
+    fn from(info: InfoRef<'a>) -> Self {
+        if info.attributes.is_none() && info.extras.is_empty() {
+            return None;
+        }
+
+        let mut ret = info.attributes.cloned().unwrap_or_default();
+        ret.extend(info.extras.into_iter().map(|(k, v)| (k.to_string(), v)));
+
+        Some(ret)
+    }
+}
+
 impl ManycoreSystem {
     /// Wrapper to generate an [`InfoError`][ManycoreErrorKind::InfoError].
     fn info_error(&self, reason: &'static str) -> ManycoreError {
         ManycoreError::new(ManycoreErrorKind::InfoError(reason))
     }
 
-    /// Gets all available info for specific core or router.
-    /// group_id looks something like "r1" or "c20", where r (router) and c (core) symbolise the variant,
-    /// and the number is the element's index.
+    /// Gets all available info for specific core, router or link.
+    /// group_id looks something like "r1", "c20" or "l2_North", where r (router), c (core) and l (link)
+    /// symbolise the variant, and the number is the element's index. Links also specify their direction.
     pub fn get_core_router_specific_info(
         &self,
-        ref group_id: String,
+        group_id: String,
     ) -> Result<Option<BTreeMap<String, String>>, ManycoreError> {
+        Ok(self.get_core_router_specific_info_ref(&group_id)?.into())
+    }
+
+    /// Borrowing counterpart of [`ManycoreSystem::get_core_router_specific_info`].
+    /// Avoids cloning the element's attributes map, which is handy for high-frequency requests.
+    pub fn get_core_router_specific_info_ref(
+        &self,
+        group_id: &str,
+    ) -> Result<InfoRef<'_>, ManycoreError> {
         if group_id.len() == 0 {
             return Err(self.info_error("Empty group_id."));
         };
@@ -31,8 +89,11 @@ impl ManycoreSystem {
             self.info_error("Something went wrong retrieving this element's information."),
         )?;
 
-        // Variant is out of iterator
-        let numerical_id = group_id.as_str();
+        // Variant is out of iterator. Links carry their direction after an underscore.
+        let (numerical_id, direction) = match group_id.as_str().split_once('_') {
+            Some((numerical_id, direction)) => (numerical_id, Some(direction)),
+            None => (group_id.as_str(), None),
+        };
 
         let core: &Core = self
             .cores()
@@ -44,52 +105,64 @@ impl ManycoreSystem {
             )
             .ok_or(self.info_error("Invalid index."))?;
 
-        // id and allocated_task are not part of the core "other_attributes" field so we shall
-        // add them manually.
-        let insert_core_default = |mut tree: BTreeMap<String, String>| {
-            tree.insert(ID_KEY.into(), core.id().to_string());
-
-            if let Some(task_id) = core.allocated_task() {
-                tree.insert(TASK_KEY.into(), task_id.to_string());
-            }
-
-            tree
-        };
-
-        match variant_char {
-            'r' => {
+        match (variant_char, direction) {
+            ('r', None) => {
                 // All relevant router info is already stored in the "other_attributes" map.
-                let attributes_clone = core.router().other_attributes().clone();
-
-                Ok(attributes_clone)
+                Ok(InfoRef {
+                    attributes: core.router().other_attributes().as_ref(),
+                    extras: BTreeMap::new(),
+                })
             }
-            'c' => {
-                let attributes_clone = core.other_attributes().clone();
+            ('c', None) => {
+                // id and allocated_task are not part of the core "other_attributes" field so we shall
+                // add them manually.
+                let mut extras = BTreeMap::from([(ID_KEY, core.id().to_string())]);
 
-                // We clone the core's map and insert missing fields.
-                match attributes_clone {
-                    Some(attributes) => Ok(Some(insert_core_default(attributes))),
-                    None => Ok(Some(insert_core_default(BTreeMap::new()))),
+                if let Some(task_id) = core.allocated_task() {
+                    extras.insert(TASK_KEY, task_id.to_string());
                 }
+
+                Ok(InfoRef {
+                    attributes: core.other_attributes().as_ref(),
+                    extras,
+                })
+            }
+            ('l', Some(direction)) => {
+                let direction = Directions::try_from(direction)
+                    .map_err(|_| self.info_error("Invalid channel ID."))?;
+
+                // All relevant link info is already stored in the "other_attributes" map.
+                let channel = core
+                    .channels()
+                    .channel()
+                    .get(&direction)
+                    .ok_or(self.info_error(
+                    "Channel direction mismatch: Could not retrieve this channel's information.",
+                ))?;
+
+                Ok(InfoRef {
+                    attributes: channel.other_attributes().as_ref(),
+                    extras: BTreeMap::new(),
+                })
             }
-            // 'l' => {
-            //     let direction: Directions = (*group_split
-            //         .get(1)
-            //         .ok_or(self.info_error("Invalid channel ID."))?)
-            //     .try_into()?;
-
-            //     // All relevant link info is already stored in the "other_attributes" map.
-            //     let attributes_clone = core
-            //         .channels()
-            //         .channel()
-            //         .get(&direction)
-            //         .ok_or(self.info_error("Channel direction mismatch: Could not retrieve this channel's information."))?
-            //         .other_attributes()
-            //         .clone();
-
-            //     Ok(attributes_clone)
-            // }
             _ => Err(self.info_error("Invalid variant.")),
         }
     }
+
+    /// Bulk counterpart of [`ManycoreSystem::get_core_router_specific_info`].
+    /// Returns a map from each requested group_id to its info. Fails on the first malformed group_id.
+    pub fn get_many_info(
+        &self,
+        group_ids: &[String],
+    ) -> Result<BTreeMap<String, Option<BTreeMap<String, String>>>, ManycoreError> {
+        group_ids
+            .iter()
+            .map(|group_id| {
+                Ok((
+                    group_id.clone(),
+                    self.get_core_router_specific_info_ref(group_id)?.into(),
+                ))
+            })
+            .collect()
+    }
 }
@@ -1,27 +1,34 @@
 //! A parser for Manycore System XML configuration files
 
+mod binary;
 mod borders;
 mod channels;
 mod configurable_attributes;
 mod cores;
+mod editing;
 mod error;
 mod graph;
 mod info;
 mod router;
 mod routing;
+mod snapshot;
+mod statistics;
 mod tests;
 mod utils;
+mod validation;
 
 use std::collections::BTreeMap;
-use std::collections::HashMap;
 
 pub use crate::borders::*;
 pub use crate::channels::*;
 pub use crate::cores::*;
+pub use crate::editing::*;
 pub use crate::error::*;
 pub use crate::graph::*;
+pub use crate::info::*;
 pub use crate::router::*;
 pub use crate::routing::*;
+use crate::snapshot::Snapshot;
 pub use configurable_attributes::*;
 use getset::{Getters, MutGetters, Setters};
 use quick_xml::DeError;
@@ -32,36 +39,46 @@ pub static COORDINATES_KEY: &'static str = "@coordinates";
 pub static BORDER_ROUTERS_KEY: &'static str = "@borderRouters";
 pub static ROUTING_KEY: &'static str = "@routingAlgorithm";
 pub static TASK_COST_KEY: &'static str = "@taskCost";
+pub static POWER_KEY: &str = "@power";
+pub static CURRENT_LOAD_KEY: &str = "@currentLoad";
 
 /// Type for rows and columns
 pub type SystemDimensionsT = u8;
 /// Type for Element IDs
 pub type ElementIDT = u16;
-/// Type that can fully contain [`SystemDimensionsT`] + negative space.
-/// Must also contain [`ElementIDT`].
-type WrappingSystemDimensionsT = i32;
+/// Type for Task IDs
+pub type TaskIDT = u16;
 
-/// Panic message to throw when converting SystemDimensionsT/ElementIDT to an
-/// index type and it does not fit.
+/// Error message reported by [`to_index`] when converting SystemDimensionsT/ElementIDT
+/// to an index type and it does not fit.
 /// Conversion fails when target machine address space cannot index the cores
-/// vector. Change panic message if system dimensions are modified.
+/// vector. Change this message if system dimensions are modified.
 /// Current values fit in a 32-bit machine. Technically, 16-bit machine should
 /// do but they tend to be weird and this crate does not account for any of
 /// their possible weirdness.
 pub(crate) const UNSUPPORTED_PLATFORM: &'static str =
     "manycore_parser supports 32-bit address space and up.";
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Getters, Setters, MutGetters)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Getters, Setters, MutGetters, Clone)]
 #[serde(rename_all = "PascalCase")]
 /// Object representation of a ManyCore System as provided in input XML file.
 pub struct ManycoreSystem {
     #[serde(rename = "@xmlns")]
+    #[getset(get = "pub")]
+    /// The XML namespace.
     xmlns: String,
     #[serde(rename = "@xmlns:xsi")]
+    #[getset(get = "pub")]
+    /// The XML schema instance namespace.
     xmlns_si: String,
     // Not sure why deserialisation fails for xsi:schemaLocation but serialisation succeeds.
     // Either way, this works and I guess it's just a quick-xml quirk.
-    #[serde(rename(serialize = "@xsi:schemaLocation", deserialize = "@schemaLocation"))]
+    #[serde(
+        rename(serialize = "@xsi:schemaLocation", deserialize = "@schemaLocation"),
+        alias = "@xsi:schemaLocation"
+    )]
+    #[getset(get = "pub")]
+    /// The XML schema location.
     xsi_schema_location: String,
     #[getset(get = "pub")]
     #[serde(rename = "@rows")]
@@ -94,48 +111,105 @@ pub struct ManycoreSystem {
     #[serde(skip)]
     #[getset(get = "pub", set = "pub", get_mut = "pub")]
     /// This is not part of the XML and is used in the routing logic. It maps a task ID (key) to the corresponding core ID (value, the core upon which the task is allocated to).
-    task_core_map: HashMap<u16, usize>,
+    task_core_map: BTreeMap<TaskIDT, usize>,
     #[serde(skip)]
     #[getset(get = "pub")]
     /// This is not part of the XML and is used to provided the frontend with a list of attributes that can be requested for rendering.
     configurable_attributes: ConfigurableAttributes,
+    #[serde(skip)]
+    /// Pristine copy of the system, retained on demand to support [`ManycoreSystem::reset`].
+    snapshot: Snapshot,
 }
 
 /// Wrapper function to geneate a [`ManycoreErrorKind::GenerationError`].
-fn generation_error(reason: String) -> ManycoreError {
-    ManycoreError::new(ManycoreErrorKind::GenerationError(reason))
+fn generation_error(subsystem: ErrorSubsystem, reason: String) -> ManycoreError {
+    ManycoreError::new(ManycoreErrorKind::GenerationError(subsystem, reason))
+}
+
+/// Converts a [`SystemDimensionsT`] or [`ElementIDT`] into an index type.
+/// Fails with [`UNSUPPORTED_PLATFORM`] rather than panicking if it does not fit.
+pub(crate) fn to_index<T>(value: T) -> Result<usize, ManycoreError>
+where
+    usize: TryFrom<T>,
+{
+    usize::try_from(value)
+        .map_err(|_| generation_error(ErrorSubsystem::Dimensions, UNSUPPORTED_PLATFORM.into()))
 }
 
 impl ManycoreSystem {
     /// Deserialises an XML file into a ManycoreSystem struct.
     pub fn parse_file(path: &str) -> Result<ManycoreSystem, ManycoreError> {
-        let file_content =
-            std::fs::read_to_string(path).map_err(|e| generation_error(e.to_string()))?;
-
-        let mut manycore: ManycoreSystem =
-            quick_xml::de::from_str(&file_content).map_err(|e| generation_error(e.to_string()))?;
-
-        // Sanitise rows and columns
-        // if manycore.columns < 0 || manycore.rows < 0 {
-        //     return Err(generation_error(format!(
-        //         "Manycore {} cannot be negative",
-        //         if manycore.columns < 0 {
-        //             "columns"
-        //         } else {
-        //             "rows"
-        //         }
-        //     )));
-        // }
+        let file_content = std::fs::read_to_string(path)
+            .map_err(|e| generation_error(ErrorSubsystem::System, e.to_string()))?;
+
+        ManycoreSystem::parse_str(&file_content)
+    }
+
+    /// Deserialises an XML string into a ManycoreSystem struct.
+    pub fn parse_str(xml: &str) -> Result<ManycoreSystem, ManycoreError> {
+        let manycore: ManycoreSystem = quick_xml::de::from_str(xml)
+            .map_err(|e| generation_error(ErrorSubsystem::System, e.to_string()))?;
+
+        manycore.finalize()
+    }
+
+    /// Deserialises XML read from `reader` (e.g. a file handle, a socket or a decompression
+    /// stream) into a ManycoreSystem struct, without buffering the whole document first.
+    pub fn parse_reader<R: std::io::Read>(reader: R) -> Result<ManycoreSystem, ManycoreError> {
+        let manycore: ManycoreSystem = quick_xml::de::from_reader(std::io::BufReader::new(reader))
+            .map_err(|e| generation_error(ErrorSubsystem::System, e.to_string()))?;
+
+        manycore.finalize()
+    }
+
+    /// Returns a canned, valid 3x3 system equivalent to the crate's `VisualiserOutput1.xml`
+    /// test fixture. Handy to test against a known system without bundling any XML.
+    pub fn example_3x3() -> ManycoreSystem {
+        ManycoreSystem::parse_str(include_str!("../tests/VisualiserOutput1.xml"))
+            .expect("The bundled example system is valid.")
+    }
+
+    /// Whether the cores matrix has as many rows as columns.
+    pub fn is_square(&self) -> bool {
+        self.rows == self.columns
+    }
+
+    /// Returns the (task id, core id) allocation pairs, sorted by task id.
+    /// Tasks allocated on sinks or sources are not part of the mapping.
+    pub fn mapping(&self) -> Vec<(TaskIDT, ElementIDT)> {
+        self.task_core_map
+            .iter()
+            .filter_map(|(task_id, core_idx)| {
+                self.cores
+                    .list()
+                    .get(*core_idx)
+                    .map(|core| (*task_id, *core.id()))
+            })
+            .collect()
+    }
+
+    /// Validates a freshly deserialised system and computes the fields that are not part of the XML.
+    fn finalize(self) -> Result<ManycoreSystem, ManycoreError> {
+        let mut manycore = self;
+
+        // Rows and columns are unsigned, so the deserialiser already rejects negative values.
+        // A zero dimension would expect no cores at all.
+        for (dimension, value) in [("rows", manycore.rows), ("columns", manycore.columns)] {
+            if value == 0 {
+                return Err(generation_error(
+                    ErrorSubsystem::Dimensions,
+                    format!("Manycore {dimension} must be at least 1, found 0."),
+                ));
+            }
+        }
 
         // Dimensions in ID type
         manycore.columns_in_id_space = ElementIDT::from(manycore.columns);
         manycore.rows_in_id_space = ElementIDT::from(manycore.rows);
 
-        let expected_number_of_cores = usize::try_from(manycore.columns)
-            .expect(UNSUPPORTED_PLATFORM)
-            * usize::try_from(manycore.rows).expect(UNSUPPORTED_PLATFORM);
+        let expected_number_of_cores = to_index(manycore.columns)? * to_index(manycore.rows)?;
         if manycore.cores().list().len() != expected_number_of_cores {
-            return Err(generation_error(format!("Expected {expected_number_of_cores} cores, found {}. Hint: make sure you provided the correct number of rows ({}) and columns ({}).", manycore.cores.list().len(), manycore.rows, manycore.columns)));
+            return Err(generation_error(ErrorSubsystem::Cores, format!("Expected {expected_number_of_cores} cores, found {}. Hint: make sure you provided the correct number of rows ({}) and columns ({}).", manycore.cores.list().len(), manycore.rows, manycore.columns)));
         }
 
         // Sort cores by id. This is potentially unnecessary if the file contains,
@@ -157,12 +231,8 @@ impl ManycoreSystem {
         // Manually insert channel attributes that are not part of the "other_attributes" map.
         channel_attributes.insert_manual(ROUTING_KEY, AttributeType::Routing);
 
-        // Core id validation tracker
-        let mut prev_id: WrappingSystemDimensionsT = -1;
-
-        let last = manycore.cores.list().len() - 1;
-        let mut task_core_map = HashMap::new();
-        for i in 0..=last {
+        let mut task_core_map = BTreeMap::new();
+        for i in 0..manycore.cores.list().len() {
             let columns = manycore.columns_in_id_space;
             let rows = manycore.rows_in_id_space;
 
@@ -171,40 +241,69 @@ impl ManycoreSystem {
                 .list_mut()
                 .get_mut(i)
                 .ok_or(generation_error(
+                    ErrorSubsystem::Cores,
                     "Something went wrong inspecting Core data.".into(),
                 ))?;
 
+            // Cores are sorted, so a duplicate ID sits right after its first occurrence.
+            if i > 0 && usize::from(*core.id()) == i - 1 {
+                return Err(generation_error(
+                    ErrorSubsystem::Cores,
+                    format!("Duplicate core ID {}: core IDs must be unique.", core.id()),
+                ));
+            }
+
             // Validate IDs follow incrementing sequence starting from zero: 0 -> 1 -> 2 -> etc.
-            let validation_id = WrappingSystemDimensionsT::from(*core.id());
-            if (validation_id - prev_id) != 1 {
-                return Err(generation_error(format!(
-                    "Core IDs must be incremental starting from 0{}",
-                    if prev_id > -1 {
-                        format!(
-                            ". Was expecting ID {}, got {}. Previously inspected core had ID {}.",
-                            prev_id + 1,
-                            validation_id,
-                            prev_id
-                        )
-                    } else {
-                        ".".to_string()
-                    }
-                )));
+            // Cores are sorted, so the core at index i must have ID i.
+            if usize::from(*core.id()) != i {
+                return Err(generation_error(
+                    ErrorSubsystem::Cores,
+                    format!(
+                        "Core IDs must be incremental starting from 0{}",
+                        if i > 0 {
+                            format!(
+                                ". Was expecting ID {i}, got {}. Previously inspected core had ID {}.",
+                                core.id(),
+                                i - 1
+                            )
+                        } else {
+                            ".".to_string()
+                        }
+                    ),
+                ));
             }
-            prev_id += 1;
+
+            // Some exporters nest channels under the router
+            core.adopt_router_channels();
 
             // Matrix edge
             core.populate_matrix_edge(columns, rows);
 
-            // task -> core map
+            // Coordinates, if any, must agree with the declared dimensions
+            core.validate_coordinates(columns, rows)?;
+
+            // task -> core map. A task can only be allocated once.
             if let Some(task_id) = core.allocated_task().as_ref() {
-                task_core_map.insert(*task_id, i);
+                if let Some(previous) = task_core_map.insert(*task_id, i) {
+                    return Err(generation_error(
+                        ErrorSubsystem::Cores,
+                        format!(
+                            "Task {task_id} is allocated on both core {previous} and core {}.",
+                            core.id()
+                        ),
+                    ));
+                }
             }
 
             // router ID
             let core_id = *core.id();
             core.router_mut().set_id(core_id);
 
+            // Loads exported through ManycoreSystem::to_xml_with_loads
+            for channel in core.channels_mut().channel_mut().values_mut() {
+                channel.adopt_current_load()?;
+            }
+
             // Populate attribute maps
             core_attributes.extend_from_element(core);
             router_attributes.extend_from_element(core.router());
@@ -213,17 +312,18 @@ impl ManycoreSystem {
             }
         }
 
-        // Store task->core map
-        manycore.task_core_map = task_core_map;
-
         // Populate core -> border map
         if let Some(borders) = manycore.borders_mut() {
             // Manually insert borders key in channel attributes
             channel_attributes.insert_manual(BORDER_ROUTERS_KEY, AttributeType::Boolean);
 
+            borders.validate_allocations(&task_core_map)?;
             borders.compute_core_border_map();
         }
 
+        // Store task->core map
+        manycore.task_core_map = task_core_map;
+
         // Instantiate configurable attributes
         manycore.configurable_attributes = ConfigurableAttributes::new(
             core_attributes,
@@ -237,17 +337,188 @@ impl ManycoreSystem {
     }
 }
 
+/// Borrowed view of a [`ManycoreSystem`] that serialises without the namespace attributes.
+#[derive(Serialize)]
+#[serde(rename = "ManycoreSystem", rename_all = "PascalCase")]
+struct BareManycoreSystem<'a> {
+    #[serde(rename = "@rows")]
+    rows: &'a SystemDimensionsT,
+    #[serde(rename = "@columns")]
+    columns: &'a SystemDimensionsT,
+    #[serde(rename = "@routingAlgo", skip_serializing_if = "Option::is_none")]
+    routing_algo: &'a Option<String>,
+    task_graph: &'a TaskGraph,
+    cores: &'a Cores,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    borders: &'a Option<Borders>,
+}
+
+/// Serialises the provided value to XML, indenting with 4 spaces and using minimal quoting.
+fn serialise_xml<T: Serialize>(value: &T) -> Result<String, DeError> {
+    let mut buf = String::new();
+    serialise_xml_into(value, &mut buf)?;
+
+    Ok(buf)
+}
+
+/// Same as [`serialise_xml`], but writes the XML into `writer` as it is produced.
+fn serialise_xml_into<T: Serialize, W: std::fmt::Write>(
+    value: &T,
+    writer: &mut W,
+) -> Result<(), DeError> {
+    let mut serialiser = quick_xml::se::Serializer::new(writer);
+    serialiser.indent(' ', 4);
+    serialiser.set_quote_level(quick_xml::se::QuoteLevel::Minimal);
+
+    value.serialize(serialiser)?;
+
+    Ok(())
+}
+
+/// Adapter letting the XML serialiser, which expects a [`std::fmt::Write`], stream into a
+/// [`std::io::Write`]. The underlying IO error, if any, is retained as [`std::fmt::Error`] carries none.
+struct IoWriteAdapter<W: std::io::Write> {
+    inner: W,
+    error: Option<std::io::Error>,
+}
+
+impl<W: std::io::Write> std::fmt::Write for IoWriteAdapter<W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            std::fmt::Error
+        })
+    }
+}
+
+impl ManycoreSystem {
+    /// Serialises the system to XML, streaming it into `writer`. The output is the same as
+    /// [`String::try_from`]'s.
+    pub fn write_to(&self, writer: impl std::io::Write) -> Result<(), ManycoreError> {
+        let mut adapter = IoWriteAdapter {
+            inner: writer,
+            error: None,
+        };
+
+        serialise_xml_into(self, &mut adapter).map_err(|e| {
+            let reason = match adapter.error.take() {
+                Some(io_error) => io_error.to_string(),
+                None => e.to_string(),
+            };
+
+            generation_error(ErrorSubsystem::System, reason)
+        })?;
+
+        adapter
+            .inner
+            .flush()
+            .map_err(|e| generation_error(ErrorSubsystem::System, e.to_string()))
+    }
+
+    /// Serialises the system to XML into the file at `path`, creating or truncating it.
+    pub fn write_to_file(&self, path: &str) -> Result<(), ManycoreError> {
+        let file = std::fs::File::create(path)
+            .map_err(|e| generation_error(ErrorSubsystem::System, e.to_string()))?;
+
+        self.write_to(std::io::BufWriter::new(file))
+    }
+
+    /// Serialises the system to JSON, e.g. for debugging. Field names follow the XML ones
+    /// (e.g. `@rows`, `Cores`), fields computed on parse or routing are not included.
+    /// See [`ManycoreSystem::write_json`] to stream the same output into a writer instead.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> Result<String, ManycoreError> {
+        let mut buf = Vec::new();
+        self.write_json(&mut buf)?;
+
+        String::from_utf8(buf).map_err(|e| generation_error(ErrorSubsystem::System, e.to_string()))
+    }
+
+    /// Serialises the system to XML without the `xmlns`, `xmlns:xsi` and `xsi:schemaLocation`
+    /// attributes. Handy when embedding the output in another document.
+    pub fn to_xml_bare(&self) -> Result<String, ManycoreError> {
+        let bare = BareManycoreSystem {
+            rows: &self.rows,
+            columns: &self.columns,
+            routing_algo: &self.routing_algo,
+            task_graph: &self.task_graph,
+            cores: &self.cores,
+            borders: &self.borders,
+        };
+
+        serialise_xml(&bare).map_err(|e| generation_error(ErrorSubsystem::System, e.to_string()))
+    }
+
+    /// Serialises the system to XML, adding each channel's non-zero current load as a
+    /// `@currentLoad` attribute. Meant to be called after [`ManycoreSystem::route`].
+    /// Parsing the output restores the loads.
+    pub fn to_xml_with_loads(&self) -> Result<String, ManycoreError> {
+        let mut manycore = self.clone();
+
+        for core in manycore.cores.list_mut() {
+            for channel in core.channels_mut().channel_mut().values_mut() {
+                let load = *channel.current_load();
+
+                if load != 0 {
+                    channel
+                        .other_attributes_mut()
+                        .get_or_insert_with(BTreeMap::new)
+                        .insert(CURRENT_LOAD_KEY.to_string(), load.to_string());
+                }
+            }
+        }
+
+        serialise_xml(&manycore)
+            .map_err(|e| generation_error(ErrorSubsystem::System, e.to_string()))
+    }
+
+    /// Serialises the system to XML, parses it back and checks the result matches the system.
+    /// Channel loads are not part of the XML and are ignored. On mismatch, the error names the
+    /// first differing section.
+    pub fn assert_roundtrip(&self) -> Result<(), ManycoreError> {
+        let xml = String::try_from(self)
+            .map_err(|e| generation_error(ErrorSubsystem::System, e.to_string()))?;
+        let reparsed = ManycoreSystem::parse_str(&xml)?;
+
+        let mut expected = self.clone();
+        expected.clear_channels();
+
+        let mismatch = if expected.xmlns != reparsed.xmlns
+            || expected.xmlns_si != reparsed.xmlns_si
+            || expected.xsi_schema_location != reparsed.xsi_schema_location
+        {
+            Some("namespaces")
+        } else if expected.rows != reparsed.rows
+            || expected.columns != reparsed.columns
+            || expected.routing_algo != reparsed.routing_algo
+        {
+            Some("system attributes")
+        } else if expected.task_graph != reparsed.task_graph {
+            Some("task graph")
+        } else if expected.cores != reparsed.cores {
+            Some("cores")
+        } else if expected.borders != reparsed.borders {
+            Some("borders")
+        } else if expected != reparsed {
+            Some("derived fields")
+        } else {
+            None
+        };
+
+        match mismatch {
+            Some(section) => Err(generation_error(
+                ErrorSubsystem::System,
+                format!("Serialised XML does not parse back to the same system: {section} differ."),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
 impl TryFrom<&ManycoreSystem> for String {
     type Error = DeError;
 
     fn try_from(manycore: &ManycoreSystem) -> Result<Self, Self::Error> {
-        let mut buf = String::new();
-        let mut serialiser = quick_xml::se::Serializer::new(&mut buf);
-        serialiser.indent(' ', 4);
-        serialiser.set_quote_level(quick_xml::se::QuoteLevel::Minimal);
-
-        manycore.serialize(serialiser)?;
-
-        Ok(buf)
+        serialise_xml(manycore)
     }
 }
@@ -3,18 +3,22 @@ use std::collections::BTreeMap;
 use getset::Setters;
 use serde::{Deserialize, Serialize};
 
-use crate::{utils, ElementIDT, WithID, WithXMLAttributes};
+use crate::{utils, Channels, ElementIDT, WithID, WithXMLAttributes, ID_KEY};
 
 #[cfg(doc)]
 use crate::Core;
 
 /// Object representation of a [`Core`]'s router.
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Setters)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Setters, Default)]
 pub struct Router {
     /// The associated core id (not part of XML).
     #[serde(skip)]
     #[getset(set = "pub")]
     id: ElementIDT,
+    /// Channels, for exporters that nest them under the router rather than the core.
+    /// Handed over to the core on parse, never serialised.
+    #[serde(rename = "Channels", default, skip_serializing)]
+    channels: Option<Channels>,
     /// Any other router attribute present in the XML.
     #[serde(
         flatten,
@@ -30,9 +34,29 @@ impl Router {
     pub fn new(id: ElementIDT, other_attributes: Option<BTreeMap<String, String>>) -> Self {
         Self {
             id,
+            channels: None,
             other_attributes,
         }
     }
+
+    /// Returns the router's attributes, including its id which is not part of the
+    /// `other_attributes` map.
+    pub fn full_attributes(&self) -> BTreeMap<String, String> {
+        let mut ret = self.other_attributes.clone().unwrap_or_default();
+        ret.insert(ID_KEY.to_string(), self.id.to_string());
+
+        ret
+    }
+
+    /// Mutable access to the router's other attributes.
+    pub(crate) fn other_attributes_mut(&mut self) -> &mut Option<BTreeMap<String, String>> {
+        &mut self.other_attributes
+    }
+
+    /// Takes the channels nested under the router, if any.
+    pub(crate) fn take_channels(&mut self) -> Option<Channels> {
+        self.channels.take()
+    }
 }
 
 impl WithXMLAttributes for Router {
@@ -1,9 +1,15 @@
-use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    io::{BufRead, BufReader, Read},
+};
 
+use getset::Getters;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    error::ManycoreError, BorderRouter, Borders, Core, Cores, Directions, Edge, ElementIDT, ManycoreErrorKind, ManycoreSystem, SinkSourceDirection, SystemDimensionsT, WithID, UNSUPPORTED_PLATFORM
+    error::ManycoreError, generation_error, to_index, BorderRouter, Borders, Core, Cores,
+    Directions, Edge, ElementIDT, ErrorSubsystem, ManycoreErrorKind, ManycoreSystem,
+    SinkSourceDirection, TaskIDT, WithID,
 };
 
 /// An enum storing all supported routing algorithms.
@@ -12,33 +18,77 @@ pub enum RoutingAlgorithms {
     Observed,
     RowFirst,
     ColumnFirst,
+    WestFirst,
+    NegativeFirst,
+    NorthLast,
+    MinimalAdaptive,
+}
+
+impl RoutingAlgorithms {
+    /// Determines whether the algorithm always yields the same routing for the same system,
+    /// i.e. whether routing results can be cached. Adaptive algorithms are not deterministic.
+    pub fn is_deterministic(&self) -> bool {
+        match self {
+            RoutingAlgorithms::Observed
+            | RoutingAlgorithms::RowFirst
+            | RoutingAlgorithms::ColumnFirst => true,
+            RoutingAlgorithms::WestFirst
+            | RoutingAlgorithms::NegativeFirst
+            | RoutingAlgorithms::NorthLast
+            | RoutingAlgorithms::MinimalAdaptive => false,
+        }
+    }
+}
+
+impl TryFrom<&str> for RoutingAlgorithms {
+    type Error = ManycoreError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "Observed" => Ok(RoutingAlgorithms::Observed),
+            "RowFirst" => Ok(RoutingAlgorithms::RowFirst),
+            "ColumnFirst" => Ok(RoutingAlgorithms::ColumnFirst),
+            "WestFirst" => Ok(RoutingAlgorithms::WestFirst),
+            "NegativeFirst" => Ok(RoutingAlgorithms::NegativeFirst),
+            "NorthLast" => Ok(RoutingAlgorithms::NorthLast),
+            "MinimalAdaptive" => Ok(RoutingAlgorithms::MinimalAdaptive),
+            _ => Err(routing_error(format!(
+                "'{value}' is not a supported routing algorithm."
+            ))),
+        }
+    }
 }
 
 /// Array used to expose supported algorithms as a configurable field.
-pub(crate) static SUPPORTED_ALGORITHMS: [RoutingAlgorithms; 3] = [
+pub(crate) static SUPPORTED_ALGORITHMS: [RoutingAlgorithms; 7] = [
     RoutingAlgorithms::Observed,
     RoutingAlgorithms::RowFirst,
     RoutingAlgorithms::ColumnFirst,
+    RoutingAlgorithms::WestFirst,
+    RoutingAlgorithms::NegativeFirst,
+    RoutingAlgorithms::NorthLast,
+    RoutingAlgorithms::MinimalAdaptive,
 ];
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Getters)]
+#[getset(get = "pub")]
 /// Provides information for routing a task graph edge.
-struct EdgeRoutingInformation {
+pub struct EdgeRoutingInformation {
     /// The source core id.
     start_id: ElementIDT,
     /// The source core column.
-    start_column: SystemDimensionsT,
+    start_column: ElementIDT,
     /// The destination core id.
     destination_id: ElementIDT,
     /// The current routing column.
-    current_column: SystemDimensionsT,
+    current_column: ElementIDT,
     /// The current routing row.
-    current_row: SystemDimensionsT,
+    current_row: ElementIDT,
     /// The destination core column.
-    destination_column: SystemDimensionsT,
+    destination_column: ElementIDT,
     /// The destination core row.
-    destination_row: SystemDimensionsT,
-    /// The edge cost.
+    destination_row: ElementIDT,
+    /// The edge load, i.e. its cost times its count.
     communication_cost: u16,
     /// The source direction, if any.
     source_direction: Option<SinkSourceDirection>,
@@ -46,13 +96,246 @@ struct EdgeRoutingInformation {
     sink_direction: Option<SinkSourceDirection>,
 }
 
+impl EdgeRoutingInformation {
+    /// Computes routing information for a route between the two given cores.
+    fn new(
+        start_id: ElementIDT,
+        destination_id: ElementIDT,
+        columns_in_id_space: &ElementIDT,
+        communication_cost: u16,
+        source_direction: Option<SinkSourceDirection>,
+        sink_direction: Option<SinkSourceDirection>,
+    ) -> Self {
+        // Workout where are we and where do we want to go in inner matrix.
+        let current_column = start_id % columns_in_id_space;
+        let start_column = current_column;
+        let current_row = start_id / columns_in_id_space;
+        let destination_column = destination_id % columns_in_id_space;
+        let destination_row = destination_id / columns_in_id_space;
+
+        Self {
+            start_id,
+            start_column,
+            destination_id,
+            current_column,
+            current_row,
+            destination_column,
+            destination_row,
+            communication_cost,
+            source_direction,
+            sink_direction,
+        }
+    }
+
+    /// Moves the current routing position one hop in the given direction.
+    /// `current_idx` is the index of the core the hop starts from and is updated accordingly.
+    fn advance(&mut self, direction: Directions, current_idx: &mut usize, columns: usize) {
+        match direction {
+            Directions::North => {
+                *current_idx -= columns;
+                self.current_row -= 1;
+            }
+            Directions::South => {
+                *current_idx += columns;
+                self.current_row += 1;
+            }
+            Directions::West => {
+                *current_idx -= 1;
+                self.current_column -= 1;
+            }
+            Directions::East => {
+                *current_idx += 1;
+                self.current_column += 1;
+            }
+        }
+    }
+
+    /// Direction to take to move towards the destination row, if not already there.
+    fn row_direction(&self) -> Option<Directions> {
+        if self.destination_row == self.current_row {
+            return None;
+        }
+
+        if self.start_id > self.destination_id {
+            // Going up
+            Some(Directions::North)
+        } else {
+            // Going down
+            Some(Directions::South)
+        }
+    }
+
+    /// Direction to take to move towards the destination column, if not already there.
+    fn column_direction(&self) -> Option<Directions> {
+        if self.destination_column == self.current_column {
+            return None;
+        }
+
+        if self.start_column > self.destination_column {
+            // Going left
+            Some(Directions::West)
+        } else {
+            // Going right
+            Some(Directions::East)
+        }
+    }
+}
+
+/// RowFirst algorithm implementation. Reaches the destination row first, then the column.
+fn row_first(eri: &EdgeRoutingInformation, _: &Core) -> Option<Directions> {
+    eri.row_direction().or_else(|| eri.column_direction())
+}
+
+/// ColumnFirst algorithm implementation. Reaches the destination column first, then the row.
+fn column_first(eri: &EdgeRoutingInformation, _: &Core) -> Option<Directions> {
+    eri.column_direction().or_else(|| eri.row_direction())
+}
+
+/// Outcome of routing a single task graph edge.
+struct RoutedEdge {
+    /// The source core id.
+    start_id: ElementIDT,
+    /// The destination core id.
+    destination_id: ElementIDT,
+    /// Hops taken, as (core id, outgoing direction) pairs.
+    hops: Vec<(ElementIDT, Directions)>,
+    /// The destination core's channel towards the sink, if the edge ends on a sink.
+    sink_direction: Option<Directions>,
+}
+
+impl RoutedEdge {
+    /// Determines whether the routed path includes the given core.
+    fn includes_core(&self, core_id: ElementIDT) -> bool {
+        self.start_id == core_id
+            || self.destination_id == core_id
+            || self.hops.iter().any(|(id, _)| *id == core_id)
+    }
+}
+
 /// Enum to differentiate type of routing packets.
-#[derive(Eq, Hash, PartialEq, Clone, Debug, PartialOrd, Ord)]
+#[derive(Serialize, Eq, Hash, PartialEq, Clone, Debug, PartialOrd, Ord)]
 pub enum RoutingType {
     OutputChannel,
     SourceChannel,
 }
 
+/// Aggregate load statistics of a routed system. See [`ManycoreSystem::routing_summary`].
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[getset(get = "pub")]
+pub struct RoutingSummary {
+    /// Sum of all channel loads.
+    total_network_load: u32,
+    /// Sum of all source channel loads.
+    total_source_load: u32,
+    /// The most loaded channel as (core id, direction, load), if any channel is loaded.
+    /// Ties go to the channel found first, by core id then direction.
+    peak_channel: Option<(ElementIDT, Directions, u16)>,
+    /// Summed outgoing channel load of every core.
+    per_core_load: BTreeMap<ElementIDT, u16>,
+}
+
+/// Picks the least loaded of the given candidate directions on the provided core.
+/// Ties are broken according to `priority` (earlier is preferred).
+fn least_loaded_of<I>(candidates: I, core: &Core, priority: &[Directions; 4]) -> Option<Directions>
+where
+    I: IntoIterator<Item = Directions>,
+{
+    candidates.into_iter().min_by_key(|direction| {
+        let load = core
+            .channels()
+            .channel()
+            .get(direction)
+            .map_or(u16::MAX, |channel| *channel.current_load());
+        let rank = priority.iter().position(|p| p == direction);
+
+        (load, rank.unwrap_or(priority.len()))
+    })
+}
+
+/// Among the directions that bring the packet closer to its destination, picks the one whose
+/// channel currently carries the least load. Ties are broken according to `priority` (earlier is preferred).
+fn least_loaded(
+    eri: &EdgeRoutingInformation,
+    core: &Core,
+    priority: &[Directions; 4],
+) -> Option<Directions> {
+    least_loaded_of(
+        [eri.row_direction(), eri.column_direction()]
+            .into_iter()
+            .flatten(),
+        core,
+        priority,
+    )
+}
+
+/// Tie-breaking priority used by the turn model algorithms.
+static TURN_MODEL_PRIORITY: [Directions; 4] = [
+    Directions::North,
+    Directions::South,
+    Directions::West,
+    Directions::East,
+];
+
+/// WestFirst turn model implementation. All westbound hops are taken first, as turning West is
+/// forbidden afterwards. The remaining hops pick the least loaded of the productive directions.
+fn west_first(eri: &EdgeRoutingInformation, core: &Core) -> Option<Directions> {
+    match eri.column_direction() {
+        Some(Directions::West) => Some(Directions::West),
+        _ => least_loaded(eri, core, &TURN_MODEL_PRIORITY),
+    }
+}
+
+/// NegativeFirst turn model implementation. Hops towards lower coordinates (North, West) are
+/// all taken before those towards higher ones (South, East). Within each phase, the least
+/// loaded of the productive directions is picked.
+fn negative_first(eri: &EdgeRoutingInformation, core: &Core) -> Option<Directions> {
+    let productive = [eri.row_direction(), eri.column_direction()];
+    let negative = productive
+        .into_iter()
+        .flatten()
+        .filter(|direction| matches!(direction, Directions::North | Directions::West));
+
+    least_loaded_of(negative, core, &TURN_MODEL_PRIORITY)
+        .or_else(|| least_loaded_of(productive.into_iter().flatten(), core, &TURN_MODEL_PRIORITY))
+}
+
+/// NorthLast turn model implementation. Northbound hops are deferred to the end of the path,
+/// the least loaded of the other productive directions is picked until then.
+fn north_last(eri: &EdgeRoutingInformation, core: &Core) -> Option<Directions> {
+    let others = [eri.row_direction(), eri.column_direction()]
+        .into_iter()
+        .flatten()
+        .filter(|direction| *direction != Directions::North);
+
+    least_loaded_of(others, core, &TURN_MODEL_PRIORITY).or_else(|| eri.row_direction())
+}
+
+/// MinimalAdaptive algorithm implementation. Whenever both a row and a column hop bring the packet
+/// closer to its destination, the least loaded channel is picked, with ties going to the row hop.
+/// Only productive hops are taken, so the destination is always reached.
+fn minimal_adaptive(eri: &EdgeRoutingInformation, core: &Core) -> Option<Directions> {
+    least_loaded(eri, core, &TURN_MODEL_PRIORITY)
+}
+
+/// Type of a deterministic algorithm's hop direction chooser.
+/// Returns [`None`] once the destination is reached.
+type DirectionChooser = fn(&EdgeRoutingInformation, &Core) -> Option<Directions>;
+
+/// Returns the hop direction chooser implementing the requested algorithm.
+fn direction_chooser(algorithm: &RoutingAlgorithms) -> Result<DirectionChooser, ManycoreError> {
+    match algorithm {
+        RoutingAlgorithms::RowFirst => Ok(row_first),
+        RoutingAlgorithms::ColumnFirst => Ok(column_first),
+        RoutingAlgorithms::WestFirst => Ok(west_first),
+        RoutingAlgorithms::NegativeFirst => Ok(negative_first),
+        RoutingAlgorithms::NorthLast => Ok(north_last),
+        RoutingAlgorithms::MinimalAdaptive => Ok(minimal_adaptive),
+        RoutingAlgorithms::Observed => Err(routing_error(
+            "Observed routing carries no per-edge path information.".into(),
+        )),
+    }
+}
+
 /// Wapper function to generate [`ManycoreErrorKind::RoutingError`].
 pub(crate) fn routing_error(reason: String) -> ManycoreError {
     ManycoreError::new(ManycoreErrorKind::RoutingError(reason))
@@ -63,7 +346,7 @@ fn no_core(i: &usize) -> ManycoreError {
     routing_error(format!("Could not get a core with ID {}.", i))
 }
 /// Wrapper function to generate a [`ManycoreErrorKind::RoutingError`] caused by not finding a [`Task`] having the requested ID.
-fn no_task(i: &u16) -> ManycoreError {
+fn no_task(i: &TaskIDT) -> ManycoreError {
     routing_error(format!(
         "Malformed TaskGraph: Task {} is not allocated on any core, sink or source.",
         i
@@ -78,8 +361,36 @@ pub(crate) fn get_core(cores: &mut Cores, i: usize) -> Result<&mut Core, Manycor
 /// Type of a successfully genereated routing result map.
 pub type RoutingMap = HashMap<ElementIDT, BTreeMap<RoutingType, BTreeSet<Directions>>>;
 
+/// Placeholder edge stream type, used when routing the task graph's own edges.
+type TaskGraphEdges = std::iter::Empty<Result<Edge, ManycoreError>>;
+
+/// Parses a `from,to,cost` line into an [`Edge`].
+fn parse_edge_line(line: &str) -> Option<Edge> {
+    let mut fields = line.split(',').map(str::trim);
+
+    let edge = Edge::new(
+        fields.next()?.parse().ok()?,
+        fields.next()?.parse().ok()?,
+        fields.next()?.parse().ok()?,
+    );
+
+    fields.next().is_none().then_some(edge)
+}
+
+/// Type of a per-channel load attribution: each (core id, direction) channel maps to the
+/// `((from, to), cost)` contributions of the task graph edges crossing it.
+pub type ChannelContributions = HashMap<(ElementIDT, Directions), Vec<((TaskIDT, TaskIDT), u16)>>;
+
+/// Type of a routed edge's path: the ordered (core id, outgoing direction) hops taken.
+pub type RoutePath = Vec<(ElementIDT, Directions)>;
+
 /// Utility function to add routing data to the routing result map.
-fn add_to_ret(key: ElementIDT, routing_type: RoutingType, direction: Directions, ret: &mut RoutingMap) {
+pub fn add_to_ret(
+    key: ElementIDT,
+    routing_type: RoutingType,
+    direction: Directions,
+    ret: &mut RoutingMap,
+) {
     ret.entry(key)
         .or_insert(BTreeMap::default())
         .entry(routing_type)
@@ -97,8 +408,7 @@ fn handle_borders(
     if let Some(source_direction) = eri.source_direction.as_ref() {
         // If so, we'll want to display load of the source channel. Add to map.
         let direction = source_direction.into();
-        // This will never panic because of negative IDs as we sanitise them on parsing.
-        let start_idx = usize::try_from(eri.start_id).expect(UNSUPPORTED_PLATFORM);
+        let start_idx = to_index(eri.start_id)?;
 
         add_to_ret(eri.start_id, RoutingType::SourceChannel, direction, ret);
 
@@ -111,8 +421,7 @@ fn handle_borders(
     if let Some(sink_direction) = eri.sink_direction.as_ref() {
         // If so, we'll want to display load of the sink channel. Add to map.
         let direction = sink_direction.into();
-        // See panic note on start_idx.
-        let destination_idx = usize::try_from(eri.destination_id).expect(UNSUPPORTED_PLATFORM);
+        let destination_idx = to_index(eri.destination_id)?;
 
         add_to_ret(
             eri.destination_id,
@@ -135,7 +444,10 @@ fn handle_borders(
 }
 
 /// Determines if the provided task_id is mapped on an edge/border router. If so, what core is it connected to and in what direction.
-fn border_task_id_to_core(borders: &Borders, task_id: u16) -> Option<(usize, SinkSourceDirection)> {
+fn border_task_id_to_core(
+    borders: &Borders,
+    task_id: TaskIDT,
+) -> Option<(usize, SinkSourceDirection)> {
     let get_data = |border: &dyn BorderRouter| -> Option<(usize, SinkSourceDirection)> {
         Some((*border.core_id(), border.direction().clone()))
     };
@@ -153,9 +465,9 @@ fn border_task_id_to_core(borders: &Borders, task_id: u16) -> Option<(usize, Sin
 
 /// Returns the core upon which the given task id is mapped.
 fn task_id_to_core<'a>(
-    task_core_map: &HashMap<u16, usize>,
-    task_id: u16,
-    borders: &mut Option<Borders>,
+    task_core_map: &BTreeMap<TaskIDT, usize>,
+    task_id: TaskIDT,
+    borders: &Option<Borders>,
     cores: &'a Cores,
 ) -> Result<(&'a Core, Option<SinkSourceDirection>), ManycoreError> {
     match task_core_map.get(&task_id) {
@@ -184,10 +496,9 @@ impl ManycoreSystem {
     fn calculate_edge_routing_information(
         cores: &Cores,
         borders: &mut Option<Borders>,
-        task_core_map: &HashMap<u16, usize>,
+        task_core_map: &BTreeMap<TaskIDT, usize>,
         edge: &Edge,
         columns_in_id_space: &ElementIDT,
-        rows_in_id_space: &ElementIDT,
     ) -> Result<EdgeRoutingInformation, ManycoreError> {
         // Retrieve core upon which source task is mapped.
         // Will take care of mapping onto core if coming from source.
@@ -197,37 +508,47 @@ impl ManycoreSystem {
         // Will take care of mapping onto core if coming from sink.
         let (destination, sink) = task_id_to_core(task_core_map, *edge.to(), borders, cores)?;
 
-        let start_id = *start.id();
-        let destination_id = *destination.id();
-
-        // Workout where are we and where do we want to go in inner matrix.
-        let current_column = SystemDimensionsT::try_from(start_id % columns_in_id_space)?;
-        let start_column = current_column.clone();
-        let current_row = SystemDimensionsT::try_from(start_id / rows_in_id_space)?;
-        let destination_column = SystemDimensionsT::try_from(destination_id % columns_in_id_space)?;
-        let destination_row = SystemDimensionsT::try_from(destination_id / rows_in_id_space)?;
+        Ok(EdgeRoutingInformation::new(
+            *start.id(),
+            *destination.id(),
+            columns_in_id_space,
+            edge.load(),
+            source,
+            sink,
+        ))
+    }
 
-        Ok(EdgeRoutingInformation {
-            start_id,
-            start_column,
-            destination_id,
-            current_column,
-            current_row,
-            destination_column,
-            destination_row,
-            communication_cost: *edge.communication_cost(),
-            source_direction: source,
-            sink_direction: sink,
+    /// Computes routing information for every task graph edge and hands it over to `f`,
+    /// along with mutable access to the cores and the routing result map.
+    /// Border routers' loads are taken care of before `f` is called.
+    fn route_edges<F>(&mut self, mut f: F) -> Result<RoutingMap, ManycoreError>
+    where
+        F: FnMut(&EdgeRoutingInformation, &mut Cores, &mut RoutingMap) -> Result<(), ManycoreError>,
+    {
+        self.route_edge_stream(None::<TaskGraphEdges>, |_, eri, cores, ret| {
+            f(eri, cores, ret)
         })
     }
 
-    /// RowFirst algorithm implementation.
-    fn row_first(&mut self) -> Result<RoutingMap, ManycoreError> {
+    /// Same as [`ManycoreSystem::route_edges`], but routes `streamed_edges` one at a time
+    /// instead of the task graph's edges, when provided. `f` is also handed the edge being routed.
+    fn route_edge_stream<I, F>(
+        &mut self,
+        streamed_edges: Option<I>,
+        mut f: F,
+    ) -> Result<RoutingMap, ManycoreError>
+    where
+        I: Iterator<Item = Result<Edge, ManycoreError>>,
+        F: FnMut(
+            &Edge,
+            &EdgeRoutingInformation,
+            &mut Cores,
+            &mut RoutingMap,
+        ) -> Result<(), ManycoreError>,
+    {
         let ManycoreSystem {
             ref mut cores,
-            ref columns,
             ref columns_in_id_space,
-            ref rows_in_id_space,
             ref task_graph,
             ref mut borders,
             ref task_core_map,
@@ -237,86 +558,30 @@ impl ManycoreSystem {
         // Return value. Stores non-zero core-edge pairs.
         let mut ret: RoutingMap = HashMap::new();
 
-        // For each edge in the task graph
-        for edge in task_graph.edges() {
-            let mut eri = ManycoreSystem::calculate_edge_routing_information(
+        let mut route_edge = |edge: &Edge| -> Result<(), ManycoreError> {
+            let eri = ManycoreSystem::calculate_edge_routing_information(
                 cores,
                 borders,
                 task_core_map,
                 edge,
                 columns_in_id_space,
-                rows_in_id_space,
             )?;
 
             handle_borders(cores, &mut ret, &eri)?;
 
-            let mut current_idx = usize::try_from(eri.start_id).expect(UNSUPPORTED_PLATFORM);
-            let mut core;
+            f(edge, &eri, cores, &mut ret)
+        };
 
-            // We must update every connection in the routers matrix
-            loop {
-                core = get_core(cores, current_idx)?;
-                let core_id = *core.id();
-
-                let channels = core.channels_mut();
-
-                if eri.destination_row != eri.current_row {
-                    // Row first
-                    if eri.start_id > eri.destination_id {
-                        // Going up
-                        add_to_ret(
-                            core_id,
-                            RoutingType::OutputChannel,
-                            Directions::North,
-                            &mut ret,
-                        );
-
-                        let _ = channels.add_to_load(eri.communication_cost, Directions::North)?;
-                        current_idx -= usize::try_from(*columns).expect(UNSUPPORTED_PLATFORM);
-                        eri.current_row -= 1;
-                    } else {
-                        // Going down
-                        add_to_ret(
-                            core_id,
-                            RoutingType::OutputChannel,
-                            Directions::South,
-                            &mut ret,
-                        );
-
-                        let _ = channels.add_to_load(eri.communication_cost, Directions::South)?;
-                        current_idx += usize::try_from(*columns).expect(UNSUPPORTED_PLATFORM);
-                        eri.current_row += 1;
-                    }
-                } else if eri.destination_column != eri.current_column {
-                    // Then column
-                    if eri.start_column > eri.destination_column {
-                        // Going left
-                        add_to_ret(
-                            core_id,
-                            RoutingType::OutputChannel,
-                            Directions::West,
-                            &mut ret,
-                        );
-
-                        let _ = channels.add_to_load(eri.communication_cost, Directions::West)?;
-                        current_idx -= 1;
-                        eri.current_column -= 1;
-                    } else {
-                        // Going right
-                        add_to_ret(
-                            core_id,
-                            RoutingType::OutputChannel,
-                            Directions::East,
-                            &mut ret,
-                        );
-
-                        let _ = channels.add_to_load(eri.communication_cost, Directions::East)?;
-                        current_idx += 1;
-                        eri.current_column += 1;
-                    }
-                } else {
-                    // We reached the destination
-                    break;
+        match streamed_edges {
+            Some(edges) => {
+                for edge in edges {
+                    route_edge(&edge?)?;
+                }
+            }
+            // For each edge in the task graph
+            None => {
+                for edge in task_graph.edges() {
+                    route_edge(edge)?;
                 }
             }
         }
@@ -324,112 +589,109 @@ impl ManycoreSystem {
         Ok(ret)
     }
 
-    /// ColumnFirst algorithm implementation.
-    fn column_first(&mut self) -> Result<RoutingMap, ManycoreError> {
-        let ManycoreSystem {
-            ref mut cores,
-            ref columns,
-            ref columns_in_id_space,
-            ref rows_in_id_space,
-            ref task_graph,
-            ref mut borders,
-            ref task_core_map,
-            ..
-        } = *self;
-
-        // Return value. Stores non-zero core-edge pairs.
-        let mut ret: RoutingMap = HashMap::new();
-
-        // For each edge in the task graph
-        for edge in task_graph.edges() {
-            let mut eri = ManycoreSystem::calculate_edge_routing_information(
-                cores,
-                borders,
-                task_core_map,
-                edge,
-                columns_in_id_space,
-                rows_in_id_space,
-            )?;
-
-            handle_borders(cores, &mut ret, &eri)?;
+    /// Routes every task graph edge, letting `next_direction` pick each hop's outgoing direction
+    /// until it returns [`None`], which signals the destination was reached.
+    /// Returns the routing result map along with each edge's routing outcome, in task graph order.
+    fn route_task_graph<F>(
+        &mut self,
+        next_direction: F,
+    ) -> Result<(RoutingMap, Vec<RoutedEdge>), ManycoreError>
+    where
+        F: FnMut(&EdgeRoutingInformation, &Core) -> Option<Directions>,
+    {
+        let mut routed_edges = Vec::with_capacity(self.task_graph.edges().len());
+
+        let ret = self.route_hops(None::<TaskGraphEdges>, next_direction, |routed_edge| {
+            routed_edges.push(routed_edge)
+        })?;
+
+        Ok((ret, routed_edges))
+    }
 
-            let mut current_idx = usize::try_from(eri.start_id).expect(UNSUPPORTED_PLATFORM);
-            let mut core;
+    /// Hop by hop routing over `streamed_edges`, or the task graph's edges if not provided.
+    /// See [`ManycoreSystem::route_task_graph`]. Each edge's routing outcome is handed over to
+    /// `on_routed` rather than collected.
+    /// A minimal path never takes more than `rows + columns` hops. Should an edge exceed that,
+    /// the system is inconsistent and an error naming the edge is returned rather than looping.
+    /// The same goes for an edge whose path crosses a missing channel.
+    fn route_hops<I, F, G>(
+        &mut self,
+        streamed_edges: Option<I>,
+        mut next_direction: F,
+        mut on_routed: G,
+    ) -> Result<RoutingMap, ManycoreError>
+    where
+        I: Iterator<Item = Result<Edge, ManycoreError>>,
+        F: FnMut(&EdgeRoutingInformation, &Core) -> Option<Directions>,
+        G: FnMut(RoutedEdge),
+    {
+        let columns = usize::from(self.columns);
+        let max_hops = usize::from(self.rows) + columns;
+
+        self.route_edge_stream(streamed_edges, |edge, eri, cores, ret| {
+            let mut eri = eri.clone();
+            let mut current_idx = to_index(eri.start_id)?;
+            let mut hops = Vec::new();
 
             // We must update every connection in the routers matrix
             loop {
-                core = get_core(cores, current_idx)?;
+                let core = get_core(cores, current_idx)?;
                 let core_id = *core.id();
 
-                let channels = core.channels_mut();
-
-                if eri.destination_column != eri.current_column {
-                    // Column first
-                    if eri.start_column > eri.destination_column {
-                        // Going left
-                        add_to_ret(
-                            core_id,
-                            RoutingType::OutputChannel,
-                            Directions::West,
-                            &mut ret,
-                        );
-
-                        let _ = channels.add_to_load(eri.communication_cost, Directions::West)?;
-                        current_idx -= 1;
-                        eri.current_column -= 1;
-                    } else {
-                        // Going right
-                        add_to_ret(
-                            core_id,
-                            RoutingType::OutputChannel,
-                            Directions::East,
-                            &mut ret,
-                        );
-
-                        let _ = channels.add_to_load(eri.communication_cost, Directions::East)?;
-                        current_idx += 1;
-                        eri.current_column += 1;
-                    }
-                } else if eri.destination_row != eri.current_row {
-                    // Then row
-
-                    if eri.start_id > eri.destination_id {
-                        // Going up
-                        add_to_ret(
-                            core_id,
-                            RoutingType::OutputChannel,
-                            Directions::North,
-                            &mut ret,
-                        );
-
-                        let _ = channels.add_to_load(eri.communication_cost, Directions::North)?;
-                        current_idx -= usize::try_from(*columns).expect(UNSUPPORTED_PLATFORM);
-                        eri.current_row -= 1;
-                    } else {
-                        // Going down
-                        add_to_ret(
-                            core_id,
-                            RoutingType::OutputChannel,
-                            Directions::South,
-                            &mut ret,
-                        );
-
-                        let _ = channels.add_to_load(eri.communication_cost, Directions::South)?;
-                        current_idx += usize::try_from(*columns).expect(UNSUPPORTED_PLATFORM);
-                        eri.current_row += 1;
-                    }
-                } else {
+                let direction = match next_direction(&eri, core) {
+                    Some(direction) => direction,
                     // We reached the destination
-                    break;
+                    None => break,
+                };
+
+                if hops.len() == max_hops {
+                    return Err(routing_error(format!(
+                        "Could not route edge {} -> {}: core {} was not reached within {max_hops} hops.",
+                        edge.from(),
+                        edge.to(),
+                        eri.destination_id
+                    )));
                 }
+
+                core.channels_mut()
+                    .add_to_load(eri.communication_cost, direction)
+                    .map_err(|_| {
+                        routing_error(format!(
+                            "Could not route edge {} -> {}: core {core_id} has no {direction} channel.",
+                            edge.from(),
+                            edge.to(),
+                        ))
+                    })?;
+
+                add_to_ret(core_id, RoutingType::OutputChannel, direction, ret);
+                hops.push((core_id, direction));
+
+                eri.advance(direction, &mut current_idx, columns);
             }
-        }
 
-        Ok(ret)
+            on_routed(RoutedEdge {
+                start_id: eri.start_id,
+                destination_id: eri.destination_id,
+                hops,
+                sink_direction: eri.sink_direction.as_ref().map(Directions::from),
+            });
+
+            Ok(())
+        })
+    }
+
+    /// Routes the task graph according to the requested deterministic algorithm.
+    fn route_deterministic(
+        &mut self,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<(RoutingMap, Vec<RoutedEdge>), ManycoreError> {
+        self.route_task_graph(direction_chooser(algorithm)?)
     }
 
     /// Observed route implementation. Mirrors Channels information.
     fn observed_route(&mut self) -> Result<RoutingMap, ManycoreError> {
+        self.can_route(&RoutingAlgorithms::Observed)?;
+
         let ManycoreSystem {
             ref mut cores,
             ref mut borders,
@@ -473,7 +735,7 @@ impl ManycoreSystem {
     }
 
     /// Clears all channel loads.
-    fn clear_channels(&mut self) {
+    pub(crate) fn clear_channels(&mut self) {
         // Zero out all links costs
         self.cores_mut().list_mut().iter_mut().for_each(|c| {
             // Channel loads
@@ -483,14 +745,394 @@ impl ManycoreSystem {
         });
     }
 
+    /// Routes the task graph using a custom routing function. `f` is called once per task graph
+    /// edge with the edge's precomputed [`EdgeRoutingInformation`], mutable access to the cores
+    /// (to update channel loads) and to the routing result map (see [`add_to_ret`]).
+    /// Channel loads are cleared beforehand and border routers are handled automatically.
+    pub fn route_with<F>(&mut self, f: F) -> Result<RoutingMap, ManycoreError>
+    where
+        F: FnMut(&EdgeRoutingInformation, &mut Cores, &mut RoutingMap) -> Result<(), ManycoreError>,
+    {
+        self.clear_channels();
+
+        self.route_edges(f)
+    }
+
+    /// Checks the system meets the requested algorithm's preconditions, without routing.
+    /// Observed routing requires the system to specify a `routingAlgo`, the others require
+    /// a full mesh (see [`ManycoreSystem::verify_full_mesh`]) and every task graph edge's
+    /// tasks to be allocated. Returns an error describing the first unmet precondition.
+    pub fn can_route(&self, algorithm: &RoutingAlgorithms) -> Result<(), ManycoreError> {
+        match algorithm {
+            RoutingAlgorithms::Observed => {
+                // Without a routing algorithm there is no observed routing to mirror.
+                if self.routing_algo.is_none() {
+                    return Err(routing_error(
+                        "Cannot mirror observed routing: the system does not specify a routingAlgo."
+                            .into(),
+                    ));
+                }
+            }
+            _ => {
+                self.verify_full_mesh()?;
+
+                for edge in self.task_graph.edges() {
+                    for task_id in [*edge.from(), *edge.to()] {
+                        task_id_to_core(&self.task_core_map, task_id, &self.borders, &self.cores)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the list of supported routing algorithms.
+    pub fn supported_algorithms() -> &'static [RoutingAlgorithms] {
+        &SUPPORTED_ALGORITHMS
+    }
+
     /// Performs routing according to the requested algorithm.
     pub fn route(&mut self, algorithm: &RoutingAlgorithms) -> Result<RoutingMap, ManycoreError> {
         self.clear_channels();
 
         match algorithm {
-            RoutingAlgorithms::ColumnFirst => self.column_first(),
-            RoutingAlgorithms::RowFirst => self.row_first(),
             RoutingAlgorithms::Observed => self.observed_route(),
+            _ => Ok(self.route_deterministic(algorithm)?.0),
+        }
+    }
+
+    /// Dry-run counterpart of [`ManycoreSystem::route`]: computes the [`RoutingMap`] on a scratch
+    /// copy of the system, leaving channel and source loads untouched.
+    pub fn simulate_route(
+        &self,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<RoutingMap, ManycoreError> {
+        self.clone().route(algorithm)
+    }
+
+    /// Dry-run counterpart of [`ManycoreSystem::route`] returning the load each channel would
+    /// carry, keyed by (core id, direction). Only loaded channels are included. Routing happens
+    /// on a scratch copy of the system, as adaptive algorithms depend on the loads accumulated
+    /// so far, so the system itself is left untouched.
+    pub fn route_dry(
+        &self,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<HashMap<(ElementIDT, Directions), u16>, ManycoreError> {
+        let mut scratch = self.clone();
+        scratch.route(algorithm)?;
+
+        Ok(scratch.direction_loads().into_iter().collect())
+    }
+
+    /// Routes the system with the algorithm of the given name (e.g. "RowFirst") and returns the
+    /// routed system as XML, channel loads included (see [`ManycoreSystem::to_xml_with_loads`]),
+    /// along with the [`RoutingMap`] as JSON.
+    #[cfg(feature = "json")]
+    pub fn route_and_export(&mut self, name: &str) -> Result<(String, String), ManycoreError> {
+        let algorithm = RoutingAlgorithms::try_from(name)?;
+
+        let routing = self.route(&algorithm)?;
+        let xml = self.to_xml_with_loads()?;
+        let json = serde_json::to_string(&routing)
+            .map_err(|e| generation_error(ErrorSubsystem::System, e.to_string()))?;
+
+        Ok((xml, json))
+    }
+
+    /// Routes the task graph adaptively: at each hop, out of the directions that bring the packet
+    /// closer to its destination, the least loaded channel is taken. When several are equally
+    /// loaded, the one appearing first in `priority` wins, making results reproducible.
+    pub fn route_adaptive(
+        &mut self,
+        priority: [Directions; 4],
+    ) -> Result<RoutingMap, ManycoreError> {
+        self.clear_channels();
+
+        Ok(self
+            .route_task_graph(|eri, core| least_loaded(eri, core, &priority))?
+            .0)
+    }
+
+    /// Summarises the current channel and source loads. Meant to be called after
+    /// [`ManycoreSystem::route`], with any algorithm.
+    pub fn routing_summary(&self) -> RoutingSummary {
+        let mut total_network_load = 0u32;
+        let mut total_source_load = 0u32;
+        let mut peak_channel: Option<(ElementIDT, Directions, u16)> = None;
+        let mut per_core_load = BTreeMap::new();
+
+        for core in self.cores.list() {
+            let mut core_load = 0u16;
+
+            for (direction, channel) in core.channels().channel() {
+                let load = *channel.current_load();
+
+                total_network_load += u32::from(load);
+                core_load = core_load.saturating_add(load);
+
+                if load > peak_channel.map_or(0, |(_, _, peak)| peak) {
+                    peak_channel = Some((*core.id(), *direction, load));
+                }
+            }
+
+            if let Some(source_loads) = core.source_loads() {
+                total_source_load += source_loads
+                    .values()
+                    .map(|load| u32::from(*load))
+                    .sum::<u32>();
+            }
+
+            per_core_load.insert(*core.id(), core_load);
         }
+
+        RoutingSummary {
+            total_network_load,
+            total_source_load,
+            peak_channel,
+            per_core_load,
+        }
+    }
+
+    /// Performs routing like [`ManycoreSystem::route`] and additionally returns the number of
+    /// channels that ended up oversubscribed, i.e. whose load exceeds their bandwidth.
+    pub fn route_checked(
+        &mut self,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<(RoutingMap, usize), ManycoreError> {
+        let ret = self.route(algorithm)?;
+
+        Ok((ret, self.overloaded_channels().len()))
+    }
+
+    /// Routes the task graph according to the requested algorithm and returns the
+    /// `(from, to)` task pairs of every edge whose path includes the given core.
+    /// Source and destination cores are part of the path.
+    pub fn edges_through_core(
+        &mut self,
+        core_id: ElementIDT,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<Vec<(TaskIDT, TaskIDT)>, ManycoreError> {
+        self.clear_channels();
+
+        let (_, routed_edges) = self.route_deterministic(algorithm)?;
+
+        Ok(self
+            .task_graph
+            .edges()
+            .iter()
+            .zip(routed_edges)
+            .filter(|(_, routed_edge)| routed_edge.includes_core(core_id))
+            .map(|(edge, _)| (*edge.from(), *edge.to()))
+            .collect())
+    }
+
+    /// Routes the task graph according to the requested algorithm and returns, for every edge
+    /// in task graph order, the ordered (core id, outgoing direction) hops taken. Edges ending on
+    /// a sink end with the hop from the destination core to the sink.
+    /// Observed routing carries no per-edge path information and is therefore not supported.
+    pub fn route_paths(
+        &mut self,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<Vec<(Edge, RoutePath)>, ManycoreError> {
+        self.clear_channels();
+
+        let (_, routed_edges) = self.route_deterministic(algorithm)?;
+
+        Ok(self
+            .task_graph
+            .edges()
+            .iter()
+            .zip(routed_edges)
+            .map(|(edge, routed_edge)| {
+                let sink_hop = routed_edge
+                    .sink_direction
+                    .map(|direction| (routed_edge.destination_id, direction));
+
+                let mut hops = routed_edge.hops;
+                hops.extend(sink_hop);
+
+                (edge.clone(), hops)
+            })
+            .collect())
+    }
+
+    /// Returns the `(from, to)` task pairs of every edge whose tasks are both allocated on the same
+    /// core. Such edges need no network routing and therefore carry no channel load.
+    /// Edges involving border routers are never intra-core.
+    pub fn intra_core_edges(&self) -> Vec<(TaskIDT, TaskIDT)> {
+        self.task_graph
+            .edges()
+            .iter()
+            .filter(|edge| {
+                match (
+                    self.task_core_map.get(edge.from()),
+                    self.task_core_map.get(edge.to()),
+                ) {
+                    (Some(from), Some(to)) => from == to,
+                    _ => false,
+                }
+            })
+            .map(|edge| (*edge.from(), *edge.to()))
+            .collect()
+    }
+
+    /// Returns the Manhattan (hop) distance between the cores hosting the given tasks.
+    /// Tasks allocated on border routers are placed on the core their border is connected to.
+    pub fn task_distance(&self, from: TaskIDT, to: TaskIDT) -> Result<u32, ManycoreError> {
+        let (from, _) = task_id_to_core(&self.task_core_map, from, &self.borders, &self.cores)?;
+        let (to, _) = task_id_to_core(&self.task_core_map, to, &self.borders, &self.cores)?;
+
+        let columns = u32::from(self.columns);
+        let (from, to) = (u32::from(*from.id()), u32::from(*to.id()));
+
+        Ok((from % columns).abs_diff(to % columns) + (from / columns).abs_diff(to / columns))
+    }
+
+    /// Aggregates the communication demand between cores: each task graph edge's load (see
+    /// [`Edge::load`]) is added to the (source core id, destination core id) entry of the cores
+    /// hosting its tasks. Tasks on border routers count as hosted by the core their border is
+    /// connected to. Edges referencing unallocated tasks are skipped.
+    pub fn core_traffic_matrix(&self) -> HashMap<(ElementIDT, ElementIDT), u32> {
+        let mut ret = HashMap::new();
+
+        for edge in self.task_graph.edges() {
+            let endpoints = task_id_to_core(
+                &self.task_core_map,
+                *edge.from(),
+                &self.borders,
+                &self.cores,
+            )
+            .and_then(|(from, _)| {
+                let (to, _) =
+                    task_id_to_core(&self.task_core_map, *edge.to(), &self.borders, &self.cores)?;
+
+                Ok((*from.id(), *to.id()))
+            });
+
+            if let Ok(endpoints) = endpoints {
+                *ret.entry(endpoints).or_insert(0) += u32::from(edge.load());
+            }
+        }
+
+        ret
+    }
+
+    /// Returns every core a packet leaving `from_core` can reach under the requested
+    /// deterministic algorithm, `from_core` included. A destination is unreachable if
+    /// its route requires a channel that is missing (e.g. a faulty link).
+    /// Channel loads are left untouched.
+    pub fn reachable_cores(
+        &self,
+        from_core: ElementIDT,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<BTreeSet<ElementIDT>, ManycoreError> {
+        let next_direction = direction_chooser(algorithm)?;
+        let columns = usize::from(self.columns);
+        let start_idx = usize::from(from_core);
+
+        if start_idx >= self.cores.list().len() {
+            return Err(no_core(&start_idx));
+        }
+
+        let mut ret = BTreeSet::new();
+
+        for destination in self.cores.list() {
+            let mut eri = EdgeRoutingInformation::new(
+                from_core,
+                *destination.id(),
+                &self.columns_in_id_space,
+                0,
+                None,
+                None,
+            );
+            let mut current_idx = start_idx;
+
+            let reachable = loop {
+                let core = self
+                    .cores
+                    .list()
+                    .get(current_idx)
+                    .ok_or(no_core(&current_idx))?;
+
+                match next_direction(&eri, core) {
+                    Some(direction) if core.channels().channel().contains_key(&direction) => {
+                        eri.advance(direction, &mut current_idx, columns);
+                    }
+                    // The route requires a missing channel
+                    Some(_) => break false,
+                    // We reached the destination
+                    None => break true,
+                }
+            };
+
+            if reachable {
+                ret.insert(*destination.id());
+            }
+        }
+
+        Ok(ret)
+    }
+
+    /// Routes the task graph according to the requested deterministic algorithm and attributes
+    /// each channel's load back to the edges it originates from. Returns a map from each loaded
+    /// (core id, direction) channel to the `((from, to), cost)` contributions of the edges
+    /// crossing it, in task graph order. Channels towards sinks are included.
+    pub fn route_attributed(
+        &mut self,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<ChannelContributions, ManycoreError> {
+        self.clear_channels();
+
+        let (_, routed_edges) = self.route_deterministic(algorithm)?;
+
+        let mut ret: ChannelContributions = HashMap::new();
+
+        for (edge, routed_edge) in self.task_graph.edges().iter().zip(routed_edges) {
+            let contribution = ((*edge.from(), *edge.to()), edge.load());
+
+            let sink_channel = routed_edge
+                .sink_direction
+                .map(|direction| (routed_edge.destination_id, direction));
+
+            for channel in routed_edge.hops.into_iter().chain(sink_channel) {
+                ret.entry(channel).or_default().push(contribution);
+            }
+        }
+
+        Ok(ret)
+    }
+
+    /// Routes edges read one at a time from `edges_reader` rather than the task graph's, keeping
+    /// memory bounded for huge workloads. Each line holds an edge as `from,to,cost`, without
+    /// any header. Empty lines are skipped. The task graph itself is left untouched.
+    pub fn route_streaming<R: Read>(
+        &mut self,
+        edges_reader: R,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<RoutingMap, ManycoreError> {
+        let next_direction = direction_chooser(algorithm)?;
+
+        let edges = BufReader::new(edges_reader)
+            .lines()
+            .enumerate()
+            .filter_map(|(i, line)| match line {
+                Ok(line) if line.trim().is_empty() => None,
+                Ok(line) => Some(parse_edge_line(&line).ok_or(generation_error(
+                    ErrorSubsystem::TaskGraph,
+                    format!(
+                        "Malformed edge on line {}: '{line}'. Expected 'from,to,cost'.",
+                        i + 1
+                    ),
+                ))),
+                Err(e) => Some(Err(generation_error(
+                    ErrorSubsystem::TaskGraph,
+                    e.to_string(),
+                ))),
+            });
+
+        self.clear_channels();
+
+        self.route_hops(Some(edges), next_direction, |_| {})
     }
 }
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use crate::{generation_error, ErrorSubsystem, ManycoreError, ManycoreSystem};
+
+/// Optional pristine copy of a [`ManycoreSystem`], as it was right after parsing.
+/// It is bookkeeping rather than system data, hence it never takes part in equality checks.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Snapshot(Option<Arc<ManycoreSystem>>);
+
+impl PartialEq for Snapshot {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl ManycoreSystem {
+    /// Deserialises an XML file like [`ManycoreSystem::parse_file`], additionally retaining
+    /// a snapshot of the freshly parsed system so that [`ManycoreSystem::reset`] can restore it.
+    ///
+    /// The snapshot is a full copy of the system, so this roughly doubles its memory footprint.
+    /// Clones of the returned system share the same snapshot.
+    pub fn parse_file_with_snapshot(path: &str) -> Result<ManycoreSystem, ManycoreError> {
+        let mut manycore = ManycoreSystem::parse_file(path)?;
+        manycore.snapshot = Snapshot(Some(Arc::new(manycore.clone())));
+
+        Ok(manycore)
+    }
+
+    /// Reverts any mutation (task moves, edge cost changes, routing loads, ...) by restoring
+    /// the snapshot taken on parse. The snapshot is retained, so the system can be reset again.
+    /// Fails if the system was not parsed with [`ManycoreSystem::parse_file_with_snapshot`].
+    pub fn reset(&mut self) -> Result<(), ManycoreError> {
+        let Snapshot(Some(pristine)) = self.snapshot.clone() else {
+            return Err(generation_error(
+                ErrorSubsystem::System,
+                "Cannot reset: no snapshot was retained on parse.".into(),
+            ));
+        };
+
+        *self = (*pristine).clone();
+        self.snapshot = Snapshot(Some(pristine));
+
+        Ok(())
+    }
+}
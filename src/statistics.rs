@@ -0,0 +1,274 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{Directions, ElementIDT, ManycoreError, ManycoreSystem, WithID, WithXMLAttributes};
+
+#[cfg(doc)]
+use crate::Edge;
+
+impl ManycoreSystem {
+    /// Flattens channel loads into a single map keyed by (core id, direction).
+    /// Only non-zero loads are included. Meant to be called after [`ManycoreSystem::route`].
+    pub fn direction_loads(&self) -> BTreeMap<(ElementIDT, Directions), u16> {
+        let mut ret = BTreeMap::new();
+
+        for core in self.cores().list() {
+            for (direction, channel) in core.channels().channel() {
+                let load = *channel.current_load();
+
+                if load != 0 {
+                    ret.insert((*core.id(), *direction), load);
+                }
+            }
+        }
+
+        ret
+    }
+
+    /// Returns (core id, direction, current load, bandwidth) for every channel whose load exceeds
+    /// its bandwidth, ordered by core id. Meant to be called after [`ManycoreSystem::route`].
+    pub fn overloaded_channels(&self) -> Vec<(ElementIDT, Directions, u16, u16)> {
+        self.cores()
+            .list()
+            .iter()
+            .flat_map(|core| {
+                core.channels()
+                    .channel()
+                    .iter()
+                    .filter(|(_, channel)| channel.current_load() > channel.bandwidth())
+                    .map(|(direction, channel)| {
+                        (
+                            *core.id(),
+                            *direction,
+                            *channel.current_load(),
+                            *channel.bandwidth(),
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    /// Sums channel loads per direction, over all cores. Every direction is present, even when
+    /// it carries no load. Meant to be called after [`ManycoreSystem::route`].
+    pub fn load_by_direction(&self) -> BTreeMap<Directions, u64> {
+        let mut ret = BTreeMap::from(Directions::all().map(|direction| (direction, 0)));
+
+        for ((_, direction), load) in self.direction_loads() {
+            *ret.entry(direction).or_insert(0) += u64::from(load);
+        }
+
+        ret
+    }
+
+    /// Computes, for every direction, the ratio of its total load to the mean total load across
+    /// directions (see [`ManycoreSystem::load_by_direction`]). Values far from 1 indicate
+    /// imbalance, e.g. traffic mostly flowing South. Every direction has a ratio of 1 when no
+    /// channel carries load. Meant to be called after [`ManycoreSystem::route`].
+    pub fn direction_asymmetry(&self) -> BTreeMap<Directions, f64> {
+        let loads = self.load_by_direction();
+
+        let count = loads.len() as f64;
+        let mean = loads.values().map(|load| *load as f64).sum::<f64>() / count;
+
+        loads
+            .into_iter()
+            .map(|(direction, load)| {
+                let ratio = if mean == 0.0 { 1.0 } else { load as f64 / mean };
+
+                (direction, ratio)
+            })
+            .collect()
+    }
+
+    /// Computes each core's utilisation: its total outgoing load over its total bandwidth.
+    /// Values above 1 indicate oversubscription. A core without bandwidth has a utilisation
+    /// of 0 when it carries no load and of [`f32::INFINITY`] otherwise.
+    pub fn core_utilisation(&self) -> BTreeMap<ElementIDT, f32> {
+        self.cores()
+            .list()
+            .iter()
+            .map(|core| {
+                let load = core.total_outgoing_load();
+                let bandwidth = core.total_bandwidth();
+
+                let utilisation = match (load, bandwidth) {
+                    (0, _) => 0.0,
+                    (_, 0) => f32::INFINITY,
+                    (load, bandwidth) => load as f32 / bandwidth as f32,
+                };
+
+                (*core.id(), utilisation)
+            })
+            .collect()
+    }
+
+    /// Returns the channels whose utilisation (current load over bandwidth) exceeds `ratio`, along
+    /// with their utilisation, sorted by core id then direction. A loaded channel without
+    /// bandwidth has a utilisation of [`f32::INFINITY`]. Meant to be called after [`ManycoreSystem::route`].
+    pub fn channels_above(&self, ratio: f32) -> Vec<(ElementIDT, Directions, f32)> {
+        let mut ret = Vec::new();
+
+        for core in self.cores().list() {
+            for (direction, channel) in core.channels().channel() {
+                let utilisation = match (*channel.current_load(), *channel.bandwidth()) {
+                    (0, _) => 0.0,
+                    (_, 0) => f32::INFINITY,
+                    (load, bandwidth) => f32::from(load) / f32::from(bandwidth),
+                };
+
+                if utilisation > ratio {
+                    ret.push((*core.id(), *direction, utilisation));
+                }
+            }
+        }
+
+        ret
+    }
+
+    /// Computes the all-pairs Manhattan (hop) distance matrix of cores, indexed by core id.
+    /// Each core's position is derived from its id and the system's columns.
+    ///
+    /// The matrix holds n² entries for n cores, which adds up quickly on large meshes.
+    /// Prefer computing individual distances on demand there.
+    pub fn distance_matrix(&self) -> Vec<Vec<u32>> {
+        let columns = u32::from(self.columns);
+        let cores = u32::from(self.rows) * u32::from(self.columns);
+
+        (0..cores)
+            .map(|from| {
+                (0..cores)
+                    .map(|to| {
+                        (from % columns).abs_diff(to % columns)
+                            + (from / columns).abs_diff(to / columns)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Theoretical lower bound of the total channel load: the sum over all edges of their
+    /// load (see [`Edge::load`]) times the hop distance between their tasks. No routing algorithm
+    /// can do better on a mesh. Fails if an edge references an unallocated task.
+    pub fn minimum_total_load(&self) -> Result<u64, ManycoreError> {
+        self.task_graph()
+            .edges()
+            .iter()
+            .map(|edge| {
+                let distance = self.task_distance(*edge.from(), *edge.to())?;
+
+                Ok(u64::from(edge.load()) * u64::from(distance))
+            })
+            .sum()
+    }
+
+    /// Computes the population variance of non-zero channel loads, as a measure of how evenly
+    /// traffic is spread over the network. Lower is more balanced. Returns 0 when no channel
+    /// carries load. Meant to be called after [`ManycoreSystem::route`].
+    pub fn load_variance(&self) -> f64 {
+        let loads = self.direction_loads();
+
+        if loads.is_empty() {
+            return 0.0;
+        }
+
+        let count = loads.len() as f64;
+        let mean = loads.values().map(|load| f64::from(*load)).sum::<f64>() / count;
+
+        loads
+            .values()
+            .map(|load| (f64::from(*load) - mean).powi(2))
+            .sum::<f64>()
+            / count
+    }
+
+    /// First-order energy estimate of the routed traffic. Every unit of load on a channel costs
+    /// `bit_energy + hop_energy`. Traffic injected by sources crosses the source channel into its
+    /// core, so source loads are accounted for in the same way.
+    /// Meant to be called after [`ManycoreSystem::route`].
+    pub fn energy_estimate(&self, bit_energy: f64, hop_energy: f64) -> f64 {
+        let total_load = self
+            .cores()
+            .list()
+            .iter()
+            .map(|core| {
+                let channels_load = core.total_outgoing_load();
+                let sources_load = core
+                    .source_loads()
+                    .as_ref()
+                    .map_or(0, |loads| loads.values().copied().map(u32::from).sum());
+
+                f64::from(channels_load) + f64::from(sources_load)
+            })
+            .sum::<f64>();
+
+        total_load * (bit_energy + hop_energy)
+    }
+
+    /// Formats routing results as CSV, with a `coreId,direction,currentLoad,sourceLoad` header.
+    /// There is a row for every channel and every source feeding into a core, sorted by core id
+    /// then direction. Meant to be called after [`ManycoreSystem::route`].
+    pub fn routing_csv(&self) -> String {
+        let mut ret = String::from("coreId,direction,currentLoad,sourceLoad\n");
+
+        for core in self.cores().list() {
+            let channels = core.channels().channel();
+            let source_loads = core.source_loads().as_ref();
+
+            let directions = channels
+                .keys()
+                .chain(source_loads.into_iter().flat_map(BTreeMap::keys))
+                .collect::<BTreeSet<&Directions>>();
+
+            for direction in directions {
+                let current_load = channels
+                    .get(direction)
+                    .map_or(0, |channel| *channel.current_load());
+                let source_load = source_loads
+                    .and_then(|loads| loads.get(direction))
+                    .copied()
+                    .unwrap_or(0);
+
+                ret.push_str(&format!(
+                    "{},{direction},{current_load},{source_load}\n",
+                    core.id()
+                ));
+            }
+        }
+
+        ret
+    }
+
+    /// Reshapes cores' attributes into a columnar table. Returns the sorted union of all core
+    /// attribute keys (the header) along with a row per core, in core order. Each row holds,
+    /// for every header key, the core's value or [`None`] if the core lacks that attribute.
+    pub fn attributes_table(&self) -> (Vec<String>, Vec<Vec<Option<String>>>) {
+        let header = self
+            .cores()
+            .list()
+            .iter()
+            .filter_map(|core| core.other_attributes().as_ref())
+            .flat_map(BTreeMap::keys)
+            .cloned()
+            .collect::<BTreeSet<String>>()
+            .into_iter()
+            .collect::<Vec<String>>();
+
+        let rows = self
+            .cores()
+            .list()
+            .iter()
+            .map(|core| {
+                header
+                    .iter()
+                    .map(|key| {
+                        core.other_attributes()
+                            .as_ref()
+                            .and_then(|attributes| attributes.get(key))
+                            .cloned()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        (header, rows)
+    }
+}
@@ -1,2 +1,11 @@
+mod borders;
+mod channels;
+mod cores;
+mod editing;
+mod graph;
+mod info;
 mod lib;
-mod routing;
\ No newline at end of file
+mod routing;
+mod snapshot;
+mod statistics;
+mod validation;
@@ -0,0 +1,108 @@
+#[cfg(test)]
+use std::collections::{BTreeMap, HashMap};
+
+#[cfg(test)]
+use crate::{
+    BorderEntry, Borders, ErrorSubsystem, ManycoreErrorKind, ManycoreSystem, SinkSourceDirection,
+};
+
+#[test]
+fn can_add_source_auto() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let mut borders = Borders::new(BTreeMap::new(), BTreeMap::new(), HashMap::new());
+
+    // Core 7 sits on the bottom edge, South is the only outward direction.
+    assert_eq!(
+        borders.add_source_auto(7, 10, &manycore).unwrap(),
+        SinkSourceDirection::South
+    );
+    assert_eq!(
+        borders.sources().get(&10).unwrap().direction(),
+        &SinkSourceDirection::South
+    );
+    assert_eq!(
+        borders.core_border_map().get(&7).unwrap(),
+        &HashMap::from([(SinkSourceDirection::South, BorderEntry::Source(10))])
+    );
+
+    // Corner, ambiguous
+    assert!(borders.add_source_auto(0, 11, &manycore).is_err());
+    // Interior core
+    assert!(borders.add_source_auto(4, 12, &manycore).is_err());
+    // Task already a source
+    assert!(borders.add_source_auto(1, 10, &manycore).is_err());
+}
+
+#[test]
+fn add_source_auto_respects_parsed_borders() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let mut borders = manycore.borders().clone().unwrap();
+
+    // Core 1 North is already taken by source 0.
+    let error = borders
+        .add_source_auto(1, 10, &manycore)
+        .expect_err("Overwriting a border router should fail");
+    assert_eq!(
+        error.to_string(),
+        "Generation Error: Core 1 already has a border router on its North side."
+    );
+
+    // Task 3 is allocated on core 1.
+    let error = borders
+        .add_source_auto(7, 3, &manycore)
+        .expect_err("Allocating a task twice should fail");
+    assert_eq!(
+        error.to_string(),
+        "Generation Error: Task 3 is already allocated on core 1."
+    );
+
+    assert!(matches!(
+        error.error_kind(),
+        ManycoreErrorKind::GenerationError(ErrorSubsystem::Borders, _)
+    ));
+    assert_eq!(&borders, manycore.borders().as_ref().unwrap());
+
+    // A free side is still accepted.
+    assert_eq!(
+        borders.add_source_auto(7, 10, &manycore).unwrap(),
+        SinkSourceDirection::South
+    );
+}
+
+#[test]
+fn border_counts_are_correct() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert_eq!(manycore.border_counts(), (2, 1));
+
+    let borders = manycore.borders().as_ref().unwrap();
+    assert_eq!(borders.source_count(), 2);
+    assert_eq!(borders.sink_count(), 1);
+
+    let manycore = ManycoreSystem::parse_file("tests/HeavyWorkload.xml")
+        .expect("Could not read input test file \"tests/HeavyWorkload.xml\"");
+
+    assert_eq!(manycore.border_counts(), (0, 0));
+}
+
+#[test]
+fn border_of_task_is_correct() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert_eq!(
+        manycore.border_of_task(5),
+        Some((6, SinkSourceDirection::West, true))
+    );
+    assert_eq!(
+        manycore.border_of_task(0),
+        Some((1, SinkSourceDirection::North, false))
+    );
+    // Task 3 is allocated on core 1.
+    assert_eq!(manycore.border_of_task(3), None);
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+use crate::{
+    Channels, Core, Directions, ManycoreSystem, Router, RoutingAlgorithms, SinkSourceDirection,
+    WithXMLAttributes, CURRENT_LOAD_KEY,
+};
+
+#[test]
+fn opposite_directions_are_correct() {
+    assert_eq!(Directions::North.opposite(), Directions::South);
+    assert_eq!(Directions::South.opposite(), Directions::North);
+    assert_eq!(Directions::West.opposite(), Directions::East);
+    assert_eq!(Directions::East.opposite(), Directions::West);
+
+    assert_eq!(
+        SinkSourceDirection::North.opposite(),
+        SinkSourceDirection::South
+    );
+    assert_eq!(
+        SinkSourceDirection::South.opposite(),
+        SinkSourceDirection::North
+    );
+    assert_eq!(
+        SinkSourceDirection::West.opposite(),
+        SinkSourceDirection::East
+    );
+    assert_eq!(
+        SinkSourceDirection::East.opposite(),
+        SinkSourceDirection::West
+    );
+}
+
+#[test]
+fn all_directions_are_in_order() {
+    assert_eq!(
+        Directions::all(),
+        [
+            Directions::North,
+            Directions::South,
+            Directions::East,
+            Directions::West,
+        ]
+    );
+    assert_eq!(
+        SinkSourceDirection::all(),
+        [
+            SinkSourceDirection::North,
+            SinkSourceDirection::South,
+            SinkSourceDirection::East,
+            SinkSourceDirection::West,
+        ]
+    );
+}
+
+#[test]
+fn full_mesh_has_all_directions() {
+    let core = Core::new(
+        4,
+        3,
+        3,
+        Router::default(),
+        None,
+        Channels::full_mesh(400),
+        None,
+    );
+
+    let channels = core.channels().channel();
+    assert_eq!(channels.len(), 4);
+
+    for direction in Directions::all() {
+        let channel = channels.get(&direction).unwrap();
+
+        assert_eq!(*channel.bandwidth(), 400);
+        assert_eq!(*channel.actual_com_cost(), 0);
+        assert_eq!(*channel.current_load(), 0);
+    }
+
+    assert!(core.router().other_attributes().is_none());
+}
+
+#[test]
+fn ordered_channels_are_sorted() {
+    let channels = Channels::full_mesh(400);
+
+    assert_eq!(
+        channels
+            .ordered()
+            .into_iter()
+            .map(|channel| *channel.direction())
+            .collect::<Vec<Directions>>(),
+        vec![
+            Directions::North,
+            Directions::South,
+            Directions::West,
+            Directions::East
+        ]
+    );
+}
+
+#[test]
+fn can_parse_latency() {
+    let manycore = ManycoreSystem::parse_file("tests/ChannelLatency.xml")
+        .expect("Could not read input test file \"tests/ChannelLatency.xml\"");
+
+    let channels = manycore.cores().list()[0].channels().channel();
+
+    assert_eq!(*channels[&Directions::North].latency(), Some(5));
+    assert_eq!(*channels[&Directions::West].latency(), None);
+
+    // Latency is a named field, not an other attribute.
+    assert!(!channels[&Directions::North]
+        .other_attributes()
+        .as_ref()
+        .unwrap()
+        .contains_key("@latency"));
+}
+
+#[test]
+fn can_serialise_current_loads() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    let res = manycore
+        .to_xml_with_loads()
+        .expect("Could not serialize ManyCore");
+
+    assert!(res.contains("currentLoad=\"180\""));
+    assert!(!String::try_from(&manycore).unwrap().contains("currentLoad"));
+
+    let reparsed = ManycoreSystem::parse_str(&res).unwrap();
+
+    assert_eq!(reparsed.direction_loads(), manycore.direction_loads());
+    assert!(!reparsed
+        .configurable_attributes()
+        .channel()
+        .contains_key(CURRENT_LOAD_KEY));
+}
+
+#[test]
+fn channel_load_saturates() {
+    let mut manycore = ManycoreSystem::parse_file("tests/DenseTraffic.xml")
+        .expect("Could not read input test file \"tests/DenseTraffic.xml\"");
+
+    // Two 40000 edges cross core 0's East channel, exceeding u16::MAX.
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    assert_eq!(
+        *manycore.cores().list()[0].channels().channel()[&Directions::East].current_load(),
+        u16::MAX
+    );
+}
@@ -0,0 +1,145 @@
+#[cfg(test)]
+use std::collections::{BTreeMap, BTreeSet};
+
+#[cfg(test)]
+use crate::{
+    Channels, Core, Directions, ManycoreSystem, Router, RoutingAlgorithms, POWER_KEY, TASK_COST_KEY,
+};
+
+#[test]
+fn has_task_cost_is_correct() {
+    let with_cost = Core::new(
+        0,
+        3,
+        3,
+        Router::default(),
+        None,
+        Channels::full_mesh(400),
+        Some(BTreeMap::from([(
+            TASK_COST_KEY.to_string(),
+            "true".to_string(),
+        )])),
+    );
+    let without_cost = Core::new(
+        1,
+        3,
+        3,
+        Router::default(),
+        None,
+        Channels::full_mesh(400),
+        None,
+    );
+
+    assert!(with_cost.has_task_cost());
+    assert!(!without_cost.has_task_cost());
+}
+
+#[test]
+fn attribute_order_does_not_matter() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+    let scrambled = ManycoreSystem::parse_file("tests/ScrambledAttributes.xml")
+        .expect("Could not read input test file \"tests/ScrambledAttributes.xml\"");
+
+    assert_eq!(scrambled, manycore);
+}
+
+#[test]
+fn can_parse_router_channels() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+    let nested = ManycoreSystem::parse_file("tests/RouterChannels.xml")
+        .expect("Could not read input test file \"tests/RouterChannels.xml\"");
+
+    assert_eq!(nested, manycore);
+}
+
+#[test]
+fn active_observed_directions_are_correct() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let expected = BTreeSet::from([Directions::North, Directions::South, Directions::East]);
+
+    assert_eq!(
+        manycore.cores().list()[1].active_observed_directions(),
+        expected
+    );
+
+    // Routing does not affect observed data.
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    assert_eq!(
+        manycore.cores().list()[1].active_observed_directions(),
+        expected
+    );
+}
+
+#[test]
+fn router_full_attributes_include_id() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let expected = BTreeMap::from([
+        ("@age".to_string(), "30".to_string()),
+        ("@id".to_string(), "4".to_string()),
+        ("@status".to_string(), "Normal".to_string()),
+        ("@temperature".to_string(), "30".to_string()),
+    ]);
+
+    assert_eq!(
+        manycore.cores().list()[4].router().full_attributes(),
+        expected
+    );
+}
+
+#[test]
+fn boundary_channels_are_correct() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert!(manycore.is_boundary_channel(0, Directions::North));
+    assert!(manycore.is_boundary_channel(0, Directions::West));
+    assert!(!manycore.is_boundary_channel(0, Directions::South));
+    assert!(!manycore.is_boundary_channel(0, Directions::East));
+
+    assert_eq!(manycore.neighbour(4, Directions::North), Some(1));
+    assert_eq!(manycore.neighbour(4, Directions::South), Some(7));
+    assert_eq!(manycore.neighbour(4, Directions::West), Some(3));
+    assert_eq!(manycore.neighbour(4, Directions::East), Some(5));
+    assert!(manycore.is_boundary_channel(8, Directions::East));
+}
+
+#[test]
+fn power_milliwatts_is_correct() {
+    let with_power = |power: &str| {
+        Core::new(
+            0,
+            3,
+            3,
+            Router::default(),
+            None,
+            Channels::full_mesh(400),
+            Some(BTreeMap::from([(POWER_KEY.to_string(), power.to_string())])),
+        )
+    };
+
+    assert_eq!(with_power("0.5W").power_milliwatts(), Some(500.0));
+    assert_eq!(with_power("12.5mW").power_milliwatts(), Some(12.5));
+    assert_eq!(with_power("250uW").power_milliwatts(), Some(0.25));
+    assert_eq!(with_power("12.5").power_milliwatts(), None);
+    assert_eq!(with_power("highW").power_milliwatts(), None);
+    assert_eq!(
+        Core::new(
+            0,
+            3,
+            3,
+            Router::default(),
+            None,
+            Channels::full_mesh(400),
+            None
+        )
+        .power_milliwatts(),
+        None
+    );
+}
@@ -0,0 +1,173 @@
+#[cfg(test)]
+use std::collections::BTreeMap;
+
+#[cfg(test)]
+use crate::{
+    AttributeType, Directions, Edge, EdgePosition, ErrorSubsystem, ManycoreErrorKind,
+    ManycoreSystem, ProcessedAttribute, RoutingAlgorithms, SinkSourceDirection, WithID,
+    WithXMLAttributes,
+};
+
+#[test]
+fn can_update_channel_attribute() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let temperature_string = "@temperature".to_string();
+
+    manycore
+        .update_channel_attribute(4, Directions::East, "@temperature", "72".into())
+        .unwrap();
+    manycore
+        .update_channel_attribute(4, Directions::East, "@age", "31".into())
+        .unwrap();
+
+    let attributes = manycore.cores().list()[4]
+        .channels()
+        .channel()
+        .get(&Directions::East)
+        .unwrap()
+        .other_attributes()
+        .as_ref()
+        .unwrap();
+
+    assert_eq!(attributes.get(&temperature_string), Some(&"72".to_string()));
+    assert_eq!(attributes.get("@age"), Some(&"31".to_string()));
+    assert_eq!(
+        manycore
+            .configurable_attributes()
+            .channel()
+            .get(&temperature_string),
+        Some(&ProcessedAttribute::new(
+            &temperature_string,
+            AttributeType::Number
+        ))
+    );
+
+    assert!(manycore
+        .update_channel_attribute(9, Directions::East, "@age", "31".into())
+        .is_err());
+}
+
+#[test]
+fn can_strip_attributes() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let has_temperature = |attributes: &Option<BTreeMap<String, String>>| {
+        attributes
+            .as_ref()
+            .is_some_and(|attributes| attributes.contains_key("@temperature"))
+    };
+
+    manycore.strip_attributes(&["@temperature"]);
+
+    for core in manycore.cores().list() {
+        assert!(!has_temperature(core.other_attributes()));
+        assert!(!has_temperature(core.router().other_attributes()));
+        for channel in core.channels().channel().values() {
+            assert!(!has_temperature(channel.other_attributes()));
+        }
+    }
+
+    let configurable_attributes = manycore.configurable_attributes();
+    assert!(!configurable_attributes.core().contains_key("@temperature"));
+    assert!(!configurable_attributes
+        .router()
+        .contains_key("@temperature"));
+    // Untouched attributes are still there.
+    assert!(configurable_attributes.core().contains_key("@age"));
+}
+
+#[test]
+fn can_rename_attribute() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    manycore.rename_attribute("@age", "@lifetime");
+
+    let core = &manycore.cores().list()[1];
+    let attributes = core.other_attributes().as_ref().unwrap();
+    assert_eq!(attributes.get("@lifetime"), Some(&"394".to_string()));
+    assert!(!attributes.contains_key("@age"));
+
+    let configurable_attributes = manycore.configurable_attributes();
+    assert_eq!(
+        configurable_attributes.core().get("@lifetime"),
+        Some(&ProcessedAttribute::new(
+            &"@lifetime".to_string(),
+            AttributeType::Number
+        ))
+    );
+    assert!(!configurable_attributes.channel().contains_key("@age"));
+}
+
+#[test]
+fn can_recompact_ids() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    // Core 7 hosts task 2, so task 2 and its edges go with it.
+    manycore.cores_mut().list_mut().remove(7);
+
+    // 8 cores cannot keep the declared 3x3 layout.
+    let error = manycore
+        .clone()
+        .recompact_ids(3, 3)
+        .expect_err("8 cores do not fit 3 rows and 3 columns");
+    assert!(matches!(
+        error.error_kind(),
+        ManycoreErrorKind::GenerationError(ErrorSubsystem::Dimensions, _)
+    ));
+
+    let report = manycore.recompact_ids(4, 2).unwrap();
+
+    assert_eq!(report.dropped_tasks(), &vec![2]);
+    assert_eq!(
+        report.dropped_edges(),
+        &vec![
+            Edge::new(0, 2, 30),
+            Edge::new(1, 2, 20),
+            Edge::new(2, 3, 50)
+        ]
+    );
+
+    assert_eq!((*manycore.rows(), *manycore.columns()), (4, 2));
+    let expected_edges = [
+        EdgePosition::TopLeft,
+        EdgePosition::TopRight,
+        EdgePosition::Left,
+        EdgePosition::Right,
+        EdgePosition::Left,
+        EdgePosition::Right,
+        EdgePosition::BottomLeft,
+        EdgePosition::BottomRight,
+    ];
+    for (i, core) in manycore.cores().list().iter().enumerate() {
+        assert_eq!(usize::from(*core.id()), i);
+        assert_eq!(core.router().id(), core.id());
+        assert_eq!(core.matrix_edge().as_ref(), Some(&expected_edges[i]));
+    }
+    assert_eq!(manycore.task_core_map(), &BTreeMap::from([(3, 1), (4, 5)]));
+    assert_eq!(
+        manycore.border_of_task(5),
+        Some((6, SinkSourceDirection::West, true))
+    );
+
+    // Reparsing the output yields the same system.
+    let xml = String::try_from(&manycore).unwrap();
+    assert_eq!(ManycoreSystem::parse_str(&xml).unwrap(), manycore);
+
+    // 3 -> 4 goes 1 -> 3 -> 5. 3 -> 5 and 4 -> 5 reach the sink West of core 6 through core 7.
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+    assert_eq!(
+        manycore.direction_loads(),
+        BTreeMap::from([
+            ((1, Directions::South), 150),
+            ((3, Directions::South), 150),
+            ((5, Directions::South), 80),
+            ((6, Directions::West), 80),
+            ((7, Directions::West), 80),
+        ])
+    );
+}
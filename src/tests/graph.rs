@@ -0,0 +1,190 @@
+#[cfg(test)]
+use std::collections::BTreeMap;
+
+#[cfg(test)]
+use crate::{Edge, ManycoreSystem, Task, TaskGraph, TaskIDT};
+
+#[test]
+fn max_depth_is_correct() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    // 0 -> 2 -> 3 -> 4 -> 5
+    assert_eq!(manycore.task_graph().max_depth().unwrap(), 5);
+
+    let cyclic = TaskGraph::new(
+        BTreeMap::new(),
+        vec![
+            Edge::new(0, 1, 10),
+            Edge::new(1, 2, 10),
+            Edge::new(2, 0, 10),
+        ],
+    );
+
+    assert!(cyclic.max_depth().is_err());
+}
+
+#[test]
+fn topological_order_breaks_ties_by_id() {
+    // A FIFO queue would release 5 before 2.
+    let graph = TaskGraph::new(
+        BTreeMap::new(),
+        vec![Edge::new(0, 5, 10), Edge::new(1, 2, 10)],
+    );
+
+    assert_eq!(graph.topological_order().unwrap(), vec![0, 1, 2, 5]);
+}
+
+#[test]
+fn hotspots_are_correct() {
+    let graph = TaskGraph::new(
+        BTreeMap::new(),
+        vec![
+            Edge::new(0, 1, 10),
+            Edge::new(0, 2, 10),
+            Edge::new(0, 3, 10),
+            Edge::new(0, 4, 10),
+            Edge::new(1, 4, 10),
+        ],
+    );
+
+    assert_eq!(graph.hotspots(2), vec![(0, 0, 4)]);
+    assert_eq!(graph.hotspots(1), vec![(0, 0, 4), (4, 2, 0)]);
+}
+
+#[test]
+fn sorted_edges_are_ordered() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let endpoints = |graph: &TaskGraph| {
+        graph
+            .sorted_edges()
+            .into_iter()
+            .map(|edge| (*edge.from(), *edge.to()))
+            .collect::<Vec<(TaskIDT, TaskIDT)>>()
+    };
+
+    assert_eq!(
+        endpoints(manycore.task_graph()),
+        vec![(0, 2), (1, 2), (2, 3), (3, 4), (3, 5), (4, 5)]
+    );
+
+    let scrambled = TaskGraph::new(
+        BTreeMap::new(),
+        vec![
+            Edge::new(3, 5, 10),
+            Edge::new(0, 2, 10),
+            Edge::new(3, 4, 10),
+            Edge::new(1, 2, 10),
+        ],
+    );
+
+    assert_eq!(endpoints(&scrambled), vec![(0, 2), (1, 2), (3, 4), (3, 5)]);
+}
+
+#[test]
+fn roots_and_leaves_are_correct() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert_eq!(manycore.task_graph().roots(), vec![0, 1]);
+    assert_eq!(manycore.task_graph().leaves(), vec![5]);
+}
+
+#[test]
+fn shortest_path_is_correct() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+    let task_graph = manycore.task_graph();
+
+    // 0 -> 2 -> 3 -> 5 skips the longer route through task 4.
+    assert_eq!(task_graph.shortest_path(0, 5), Some(vec![0, 2, 3, 5]));
+    assert_eq!(task_graph.shortest_path(3, 3), Some(vec![3]));
+    // Edges are directed.
+    assert_eq!(task_graph.shortest_path(5, 0), None);
+    assert_eq!(task_graph.shortest_path(0, 42), None);
+}
+
+#[test]
+fn contiguous_ids_are_checked() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    // Tasks 0, 1 and 5 are only edge endpoints, the listed tasks are 2, 3 and 4.
+    assert!(manycore.task_graph().assert_contiguous_ids().is_ok());
+
+    let gapped = TaskGraph::new(
+        BTreeMap::from([
+            (0, Task::new(0, 10)),
+            (1, Task::new(1, 10)),
+            (3, Task::new(3, 10)),
+        ]),
+        vec![],
+    );
+
+    assert_eq!(
+        gapped.assert_contiguous_ids().unwrap_err().to_string(),
+        "Generation Error: Task IDs are not contiguous: expected task 2, found task 3 after task 1."
+    );
+}
+
+#[test]
+fn is_tree_is_correct() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    // Acyclic, but task 5 is reached through both task 3 and task 4.
+    assert!(!manycore.task_graph().is_tree());
+
+    let tree = TaskGraph::new(
+        BTreeMap::new(),
+        vec![
+            Edge::new(0, 1, 10),
+            Edge::new(0, 2, 10),
+            Edge::new(2, 3, 10),
+            Edge::new(2, 4, 10),
+        ],
+    );
+
+    assert!(tree.is_tree());
+
+    // Acyclic with the right number of edges, but task 3 is disconnected from the rest.
+    let disconnected = TaskGraph::new(
+        BTreeMap::from([(3, Task::new(3, 10))]),
+        vec![
+            Edge::new(0, 1, 10),
+            Edge::new(0, 2, 10),
+            Edge::new(1, 2, 10),
+        ],
+    );
+
+    assert!(!disconnected.is_tree());
+}
+
+#[test]
+fn weakly_connected_components_are_correct() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert_eq!(
+        manycore.task_graph().weakly_connected_components(),
+        vec![vec![0, 1, 2, 3, 4, 5]]
+    );
+
+    // Edge direction is ignored: 4 -> 3 still joins task 3's group.
+    let disjoint = TaskGraph::new(
+        BTreeMap::new(),
+        vec![
+            Edge::new(0, 2, 10),
+            Edge::new(3, 5, 10),
+            Edge::new(2, 1, 10),
+            Edge::new(4, 3, 10),
+        ],
+    );
+
+    assert_eq!(
+        disjoint.weakly_connected_components(),
+        vec![vec![0, 1, 2], vec![3, 4, 5]]
+    );
+}
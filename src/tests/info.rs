@@ -0,0 +1,120 @@
+#[cfg(test)]
+use std::collections::BTreeMap;
+
+#[cfg(test)]
+use crate::ManycoreSystem;
+
+#[test]
+fn info_ref_matches_owned_info() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    for group_id in ["c0", "c1", "r1", "c8", "r8"] {
+        let owned = manycore
+            .get_core_router_specific_info(group_id.to_string())
+            .unwrap();
+        let borrowed = manycore
+            .get_core_router_specific_info_ref(group_id)
+            .unwrap();
+
+        let borrowed_map = borrowed
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<BTreeMap<String, String>>();
+
+        assert_eq!(owned, Some(borrowed_map));
+        assert_eq!(owned, borrowed.into());
+    }
+
+    let core_info = manycore.get_core_router_specific_info_ref("c1").unwrap();
+    assert_eq!(core_info.get("@id"), Some("1"));
+    assert_eq!(core_info.get("@allocatedTask"), Some("3"));
+    assert_eq!(core_info.get("@age"), Some("394"));
+
+    assert!(manycore.get_core_router_specific_info_ref("x1").is_err());
+    assert!(manycore.get_core_router_specific_info_ref("").is_err());
+}
+
+#[test]
+fn can_get_many_info() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let group_ids = ["c0", "r1", "l2_North"].map(String::from);
+    let info = manycore.get_many_info(&group_ids).unwrap();
+
+    assert_eq!(info.len(), 3);
+    for group_id in &group_ids {
+        assert_eq!(
+            info[group_id],
+            manycore
+                .get_core_router_specific_info(group_id.clone())
+                .unwrap()
+        );
+    }
+    assert_eq!(
+        info["l2_North"].as_ref().and_then(|link| link.get("@age")),
+        Some(&"30".to_string())
+    );
+
+    let malformed = ["c0", "l2_Up", "r1"].map(String::from);
+    assert!(manycore.get_many_info(&malformed).is_err());
+}
+
+#[test]
+fn link_direction_is_case_insensitive() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let expected = manycore
+        .get_core_router_specific_info("l4_North".to_string())
+        .unwrap();
+    assert!(expected.is_some());
+
+    for group_id in ["l4_north", "l4_NORTH", "l4_n", "l4_N"] {
+        assert_eq!(
+            manycore
+                .get_core_router_specific_info(group_id.to_string())
+                .unwrap(),
+            expected
+        );
+    }
+
+    assert!(manycore
+        .get_core_router_specific_info("l4_nort".to_string())
+        .is_err());
+}
+
+#[test]
+fn can_get_info_for_multi_digit_indices() {
+    let manycore = ManycoreSystem::parse_file("tests/Grid20x20.xml")
+        .expect("Could not read input test file \"tests/Grid20x20.xml\"");
+
+    assert_eq!(
+        manycore
+            .get_core_router_specific_info("c12".to_string())
+            .unwrap(),
+        Some(BTreeMap::from([("@id".to_string(), "12".to_string())]))
+    );
+    assert_eq!(
+        manycore
+            .get_core_router_specific_info("c255".to_string())
+            .unwrap(),
+        Some(BTreeMap::from([
+            ("@allocatedTask".to_string(), "2".to_string()),
+            ("@id".to_string(), "255".to_string()),
+        ]))
+    );
+
+    // Channels without attributes have no info, but their index and direction resolve.
+    assert_eq!(
+        manycore
+            .get_core_router_specific_info("l123_South".to_string())
+            .unwrap(),
+        None
+    );
+
+    assert!(manycore
+        .get_core_router_specific_info("c400".to_string())
+        .is_err());
+}
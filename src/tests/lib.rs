@@ -1,15 +1,18 @@
 #[cfg(test)]
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     fs::read_to_string,
+    io::Cursor,
 };
 
 #[cfg(test)]
 use crate::{
-    AttributeType, AttributesMap, BorderEntry, Borders, Channel, Channels, ConfigurableAttributes,
-    Core, Cores, Directions, Edge, ElementIDT, ManycoreSystem, ProcessedAttribute, Router, Sink,
-    SinkSourceDirection, Source, Task, TaskGraph, WithID, BORDER_ROUTERS_KEY, COORDINATES_KEY,
-    ID_KEY, ROUTING_KEY, SUPPORTED_ALGORITHMS, TASK_COST_KEY,
+    classify_attribute, to_index, AttributeType, AttributesMap, BorderEntry, Borders, Channel,
+    Channels, ConfigurableAttributes, Core, Cores, Directions, Edge, ElementIDT, ElementKind,
+    ErrorSubsystem, ManycoreErrorKind, ManycoreSystem, ProcessedAttribute, Router,
+    RoutingAlgorithms, Sink, SinkSourceDirection, Snapshot, Source, SystemDimensionsT, Task,
+    TaskGraph, WithID, BORDER_ROUTERS_KEY, COORDINATES_KEY, ID_KEY, ROUTING_KEY,
+    SUPPORTED_ALGORITHMS, TASK_COST_KEY,
 };
 
 #[cfg(test)]
@@ -287,7 +290,7 @@ fn can_parse() {
         expected_channel_conf_attrs,
     );
 
-    let expected_task_core_map = HashMap::from([(3u16, 1usize), (2u16, 7usize), (4u16, 5usize)]);
+    let expected_task_core_map = BTreeMap::from([(3u16, 1usize), (2u16, 7usize), (4u16, 5usize)]);
 
     let expected_sinks = BTreeMap::from([(5, Sink::new(6, SinkSourceDirection::West, 5))]);
     let expected_sources = BTreeMap::from([
@@ -324,7 +327,8 @@ fn can_parse() {
         cores: Cores::new(expected_cores),
         task_graph: expected_graph,
         task_core_map: expected_task_core_map,
-        configurable_attributes: expected_configurable_attributes
+        configurable_attributes: expected_configurable_attributes,
+        snapshot: Snapshot::default()
     };
 
     let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
@@ -347,8 +351,324 @@ fn can_serialize() {
     // println!("{res}")
 }
 
+#[test]
+fn parse_str_matches_parse_file() {
+    let xml = read_to_string("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert_eq!(
+        ManycoreSystem::parse_str(&xml).unwrap(),
+        ManycoreSystem::parse_file("tests/VisualiserOutput1.xml").unwrap()
+    );
+    assert!(ManycoreSystem::parse_str("<ManycoreSystem").is_err());
+}
+
+#[test]
+fn parse_reader_matches_parse_file() {
+    let xml = read_to_string("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert_eq!(
+        ManycoreSystem::parse_reader(Cursor::new(xml.into_bytes())).unwrap(),
+        ManycoreSystem::parse_file("tests/VisualiserOutput1.xml").unwrap()
+    );
+    assert!(ManycoreSystem::parse_reader(Cursor::new(b"<ManycoreSystem")).is_err());
+}
+
+#[test]
+fn write_to_roundtrips() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let mut buf: Vec<u8> = Vec::new();
+    manycore.write_to(&mut buf).unwrap();
+
+    let xml = String::from_utf8(buf).expect("Invalid UTF-8");
+    assert_eq!(xml, String::try_from(&manycore).unwrap());
+    assert_eq!(ManycoreSystem::parse_str(&xml).unwrap(), manycore);
+
+    let path = std::env::temp_dir().join("manycore_parser_write_to_file.xml");
+    let path = path.to_str().expect("Invalid temporary path");
+    manycore.write_to_file(path).unwrap();
+    assert_eq!(ManycoreSystem::parse_file(path).unwrap(), manycore);
+    let _ = std::fs::remove_file(path);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn can_serialise_json() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let json: serde_json::Value =
+        serde_json::from_str(&manycore.to_json().unwrap()).expect("Invalid JSON");
+
+    assert_eq!(json["@rows"], 3);
+    assert_eq!(
+        json["Cores"]["Core"].as_array().map(Vec::len),
+        Some(manycore.cores().list().len())
+    );
+}
+
 #[test]
 fn can_validate() {
     assert!(ManycoreSystem::parse_file("tests/Validation0.xml").is_err());
     assert!(ManycoreSystem::parse_file("tests/Validation1.xml").is_err())
 }
+
+#[test]
+fn index_conversion_fits() {
+    assert_eq!(to_index(SystemDimensionsT::MAX).unwrap(), 255);
+    assert_eq!(to_index(ElementIDT::MAX).unwrap(), 65535);
+}
+
+#[test]
+fn zero_dimension_errors_cleanly() {
+    let error = ManycoreSystem::parse_file("tests/Validation2.xml")
+        .expect_err("A system with zero rows should fail");
+
+    assert_eq!(
+        error.to_string(),
+        "Generation Error: Manycore rows must be at least 1, found 0."
+    );
+    assert!(matches!(
+        error.error_kind(),
+        ManycoreErrorKind::GenerationError(ErrorSubsystem::Dimensions, _)
+    ));
+}
+
+#[test]
+fn can_parse_string_task_ids() {
+    let manycore = ManycoreSystem::parse_file("tests/StringTaskIDs.xml")
+        .expect("Could not read input test file \"tests/StringTaskIDs.xml\"");
+
+    let expected = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert_eq!(manycore, expected);
+
+    let content = read_to_string("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    for signed in ["-3", "+3"] {
+        let xml = content.replace(
+            "allocatedTask=\"3\"",
+            &format!("allocatedTask=\"{signed}\""),
+        );
+
+        let error = ManycoreSystem::parse_str(&xml).expect_err("Signed task ids should fail");
+
+        assert!(error
+            .to_string()
+            .contains(&format!("'{signed}' is not a valid task id.")));
+    }
+}
+
+#[test]
+fn exposes_namespaces() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert_eq!(
+        manycore.xmlns(),
+        "https://www.york.ac.uk/physics-engineering-technology/ManycoreSystems"
+    );
+    assert_eq!(
+        manycore.xmlns_si(),
+        "http://www.w3.org/2001/XMLSchema-instance"
+    );
+    assert!(manycore
+        .xsi_schema_location()
+        .ends_with("manycore_schema.xsd"));
+}
+
+#[test]
+fn can_serialize_bare() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let res = manycore
+        .to_xml_bare()
+        .expect("Could not serialize ManyCore");
+
+    assert!(res.starts_with("<ManycoreSystem rows=\"3\" columns=\"3\" routingAlgo=\"RowFirst\">"));
+    assert!(!res.contains("xmlns"));
+    assert!(!res.contains("schemaLocation"));
+
+    // Everything but the root element attributes must match the regular serialisation.
+    let full = String::try_from(&manycore).expect("Could not serialize ManyCore");
+    let body = |xml: &str| xml[xml.find("<TaskGraph>").unwrap()..].to_string();
+
+    assert_eq!(body(&res), body(&full));
+}
+
+#[test]
+fn can_roundtrip_bytes() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    // Routing populates loads that are not part of the XML.
+    manycore
+        .route(&RoutingAlgorithms::RowFirst)
+        .expect("Could not route");
+
+    let bytes = manycore.to_bytes().expect("Could not serialize ManyCore");
+    let reloaded = ManycoreSystem::from_bytes(&bytes).expect("Could not deserialize ManyCore");
+
+    assert_eq!(reloaded, manycore);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn can_write_json() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    manycore
+        .route(&RoutingAlgorithms::RowFirst)
+        .expect("Could not route");
+
+    let mut buf: Vec<u8> = Vec::new();
+    manycore
+        .write_json(&mut buf)
+        .expect("Could not serialize ManyCore");
+
+    let json: serde_json::Value = serde_json::from_slice(&buf).expect("Invalid JSON");
+
+    assert_eq!(json["Cores"]["Core"].as_array().map(Vec::len), Some(9));
+    // Same shape as to_json.
+    assert_eq!(buf, manycore.to_json().unwrap().into_bytes());
+}
+
+#[test]
+fn generation_errors_carry_subsystem() {
+    let error = ManycoreSystem::parse_file("tests/Validation0.xml")
+        .expect_err("Core count mismatch should fail");
+
+    assert!(matches!(
+        error.error_kind(),
+        ManycoreErrorKind::GenerationError(ErrorSubsystem::Cores, _)
+    ));
+}
+
+#[test]
+fn detects_duplicate_core_ids() {
+    let error = ManycoreSystem::parse_file("tests/DuplicateCoreID.xml")
+        .expect_err("Two cores sharing an ID should fail");
+
+    assert_eq!(
+        error.to_string(),
+        "Generation Error: Duplicate core ID 3: core IDs must be unique."
+    );
+}
+
+#[test]
+fn detects_duplicate_allocations() {
+    let error = ManycoreSystem::parse_file("tests/DuplicateAllocation.xml")
+        .expect_err("Allocating a task twice should fail");
+
+    assert_eq!(
+        error.to_string(),
+        "Generation Error: Task 3 is allocated on both core 0 and core 1."
+    );
+
+    let error = ManycoreSystem::parse_file("tests/DuplicateBorderAllocation.xml")
+        .expect_err("Allocating a task on both a core and a sink should fail");
+
+    assert!(matches!(
+        error.error_kind(),
+        ManycoreErrorKind::GenerationError(ErrorSubsystem::Borders, _)
+    ));
+
+    let error = ManycoreSystem::parse_file("tests/DuplicateSink.xml")
+        .expect_err("Allocating a task on two sinks should fail");
+
+    assert_eq!(
+        error.to_string(),
+        "Generation Error: Task 5 is allocated on more than one sink."
+    );
+}
+
+#[test]
+fn example_matches_fixture() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert_eq!(ManycoreSystem::example_3x3(), manycore);
+}
+
+#[test]
+fn is_square_is_correct() {
+    let mut manycore = ManycoreSystem::example_3x3();
+
+    assert!(manycore.is_square());
+
+    manycore.rows = 2;
+    manycore.columns = 4;
+
+    assert!(!manycore.is_square());
+}
+
+#[test]
+fn task_core_map_is_ordered() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert_eq!(
+        manycore
+            .task_core_map()
+            .keys()
+            .copied()
+            .collect::<Vec<u16>>(),
+        vec![2, 3, 4]
+    );
+}
+
+#[test]
+fn can_roundtrip() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    manycore.assert_roundtrip().unwrap();
+
+    // Channel loads are not serialised and must not affect the outcome.
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+    manycore.assert_roundtrip().unwrap();
+}
+
+#[test]
+fn classify_attribute_is_correct() {
+    assert_eq!(classify_attribute("394"), AttributeType::Number);
+    assert_eq!(classify_attribute("0"), AttributeType::Number);
+    assert_eq!(classify_attribute("30.5"), AttributeType::Text);
+    assert_eq!(classify_attribute("-4"), AttributeType::Text);
+    assert_eq!(classify_attribute("true"), AttributeType::Text);
+    assert_eq!(classify_attribute("Normal"), AttributeType::Text);
+    assert_eq!(classify_attribute(""), AttributeType::Text);
+}
+
+#[test]
+fn mapping_is_correct() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    // Tasks 0, 1 (sources) and 5 (sink) are not allocated on cores.
+    assert_eq!(manycore.mapping(), vec![(2, 7), (3, 1), (4, 5)]);
+}
+
+#[test]
+fn distinct_attribute_values_are_correct() {
+    let manycore = ManycoreSystem::example_3x3();
+
+    assert_eq!(
+        manycore.distinct_attribute_values(ElementKind::Core, "@status"),
+        BTreeSet::from(["High".to_string()])
+    );
+    assert_eq!(
+        manycore.distinct_attribute_values(ElementKind::Core, "@actualFrequency"),
+        BTreeSet::from(["High".to_string(), "Low".to_string(), "Mid".to_string()])
+    );
+    assert!(manycore
+        .distinct_attribute_values(ElementKind::Router, "@missing")
+        .is_empty());
+}
@@ -1,6 +1,10 @@
+#[cfg(test)]
+use std::collections::{BTreeMap, BTreeSet};
+
 #[cfg(test)]
 use crate::{
-    get_core, routing_error, Directions, ManycoreError, ManycoreSystem, RoutingAlgorithms,
+    add_to_ret, get_core, routing_error, Directions, EdgePosition, ManycoreError, ManycoreSystem,
+    RoutingAlgorithms, RoutingMap, RoutingType, WithID,
 };
 
 #[cfg(test)]
@@ -130,3 +134,785 @@ fn observed_is_correct() {
         get_source_load(&mut manycore, 1, Directions::North).unwrap()
     );
 }
+
+#[test]
+fn observed_requires_routing_algo() {
+    let mut manycore = ManycoreSystem::parse_file("tests/NoRoutingAlgo.xml")
+        .expect("Could not read input test file \"tests/NoRoutingAlgo.xml\"");
+
+    assert!(manycore.routing_algo().is_none());
+    assert!(manycore.route(&RoutingAlgorithms::Observed).is_err());
+    assert!(manycore.route(&RoutingAlgorithms::RowFirst).is_ok());
+    assert!(manycore.route(&RoutingAlgorithms::ColumnFirst).is_ok());
+}
+
+#[test]
+fn edges_through_core_is_correct() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert_eq!(
+        manycore
+            .edges_through_core(4, &RoutingAlgorithms::RowFirst)
+            .unwrap(),
+        vec![(0, 2), (2, 3), (3, 4), (3, 5)]
+    );
+
+    assert!(manycore
+        .edges_through_core(4, &RoutingAlgorithms::Observed)
+        .is_err());
+}
+
+#[test]
+fn supported_algorithms_are_exposed() {
+    let algorithms = ManycoreSystem::supported_algorithms();
+
+    assert_eq!(algorithms.len(), 7);
+    assert!(algorithms.contains(&RoutingAlgorithms::RowFirst));
+    assert!(algorithms.contains(&RoutingAlgorithms::WestFirst));
+}
+
+#[test]
+fn current_algorithms_are_deterministic() {
+    assert!(RoutingAlgorithms::Observed.is_deterministic());
+    assert!(RoutingAlgorithms::RowFirst.is_deterministic());
+    assert!(RoutingAlgorithms::ColumnFirst.is_deterministic());
+    assert!(!RoutingAlgorithms::WestFirst.is_deterministic());
+    assert!(!RoutingAlgorithms::NegativeFirst.is_deterministic());
+    assert!(!RoutingAlgorithms::NorthLast.is_deterministic());
+    assert!(!RoutingAlgorithms::MinimalAdaptive.is_deterministic());
+}
+
+#[test]
+fn custom_routing_matches_row_first() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let expected_map = manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+    let expected_loads = manycore.direction_loads();
+
+    let columns = usize::from(*manycore.columns());
+
+    let map = manycore
+        .route_with(|eri, cores, map| {
+            let mut idx = usize::from(*eri.start_id());
+            let mut row = *eri.current_row();
+            let mut column = *eri.current_column();
+
+            loop {
+                let direction = if row > *eri.destination_row() {
+                    Directions::North
+                } else if row < *eri.destination_row() {
+                    Directions::South
+                } else if column > *eri.destination_column() {
+                    Directions::West
+                } else if column < *eri.destination_column() {
+                    Directions::East
+                } else {
+                    break;
+                };
+
+                let core = get_core(cores, idx)?;
+                add_to_ret(*core.id(), RoutingType::OutputChannel, direction, map);
+                core.channels_mut()
+                    .add_to_load(*eri.communication_cost(), direction)?;
+
+                match direction {
+                    Directions::North => {
+                        idx -= columns;
+                        row -= 1;
+                    }
+                    Directions::South => {
+                        idx += columns;
+                        row += 1;
+                    }
+                    Directions::West => {
+                        idx -= 1;
+                        column -= 1;
+                    }
+                    Directions::East => {
+                        idx += 1;
+                        column += 1;
+                    }
+                }
+            }
+
+            Ok(())
+        })
+        .unwrap();
+
+    assert_eq!(map, expected_map);
+    assert_eq!(manycore.direction_loads(), expected_loads);
+}
+
+#[test]
+fn finds_intra_core_edges() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert!(manycore.intra_core_edges().is_empty());
+
+    // Allocate task 4 on the same core as task 3.
+    let core = manycore.task_core_map[&3];
+    manycore.task_core_map.insert(4, core);
+
+    assert_eq!(manycore.intra_core_edges(), vec![(3, 4)]);
+}
+
+#[test]
+fn reachable_cores_are_correct() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert_eq!(
+        manycore
+            .reachable_cores(4, &RoutingAlgorithms::RowFirst)
+            .unwrap(),
+        BTreeSet::from_iter(0..9)
+    );
+
+    // Core 4 has no East channel.
+    let faulted = ManycoreSystem::parse_file("tests/MissingChannel.xml")
+        .expect("Could not read input test file \"tests/MissingChannel.xml\"");
+
+    assert_eq!(
+        faulted
+            .reachable_cores(4, &RoutingAlgorithms::RowFirst)
+            .unwrap(),
+        BTreeSet::from([0, 1, 2, 3, 4, 6, 7, 8])
+    );
+    assert_eq!(
+        faulted
+            .reachable_cores(4, &RoutingAlgorithms::ColumnFirst)
+            .unwrap(),
+        BTreeSet::from([0, 1, 3, 4, 6, 7])
+    );
+
+    assert!(faulted
+        .reachable_cores(9, &RoutingAlgorithms::RowFirst)
+        .is_err());
+    assert!(faulted
+        .reachable_cores(4, &RoutingAlgorithms::Observed)
+        .is_err());
+}
+
+#[test]
+fn route_checked_counts_oversubscribed_channels() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let (_, oversubscribed) = manycore
+        .route_checked(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    assert_eq!(oversubscribed, 0);
+
+    let mut heavy = ManycoreSystem::parse_file("tests/HeavyWorkload.xml")
+        .expect("Could not read input test file \"tests/HeavyWorkload.xml\"");
+
+    // Both edges cross two channels whose bandwidth they exceed.
+    let (_, oversubscribed) = heavy.route_checked(&RoutingAlgorithms::RowFirst).unwrap();
+    assert_eq!(oversubscribed, 4);
+}
+
+#[test]
+fn adaptive_priority_breaks_ties() {
+    let mut manycore = ManycoreSystem::parse_file("tests/HeavyWorkload.xml")
+        .expect("Could not read input test file \"tests/HeavyWorkload.xml\"");
+
+    let output_channels = |ret: &RoutingMap, core_id| {
+        ret.get(&core_id)
+            .and_then(|routing| routing.get(&RoutingType::OutputChannel))
+            .cloned()
+            .unwrap_or_default()
+    };
+
+    // Edge 0 -> 3 can leave core 0 either South or East, both unloaded.
+    let ret = manycore
+        .route_adaptive([
+            Directions::South,
+            Directions::East,
+            Directions::West,
+            Directions::North,
+        ])
+        .unwrap();
+    assert_eq!(
+        output_channels(&ret, 0),
+        BTreeSet::from([Directions::South])
+    );
+
+    // Preferring East sends edge 0 -> 3 through core 1. Edge 1 -> 2 then avoids core 1's
+    // loaded South channel and goes through core 0 instead.
+    let ret = manycore
+        .route_adaptive([
+            Directions::East,
+            Directions::West,
+            Directions::South,
+            Directions::North,
+        ])
+        .unwrap();
+    assert_eq!(
+        output_channels(&ret, 0),
+        BTreeSet::from([Directions::South, Directions::East])
+    );
+}
+
+#[test]
+fn route_attributed_contributions_sum_to_load() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let attributed = manycore
+        .route_attributed(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+
+    let loads = manycore.direction_loads();
+    assert_eq!(attributed.len(), loads.len());
+
+    for ((core_id, direction), contributions) in &attributed {
+        let total: u16 = contributions.iter().map(|(_, cost)| cost).sum();
+
+        assert_eq!(loads[&(*core_id, *direction)], total);
+    }
+
+    // Core 1 South carries edges 0 -> 2, 3 -> 4 and 3 -> 5.
+    assert_eq!(
+        attributed[&(1, Directions::South)],
+        vec![((0, 2), 30), ((3, 4), 100), ((3, 5), 50)]
+    );
+}
+
+#[test]
+fn streaming_matches_task_graph_routing() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let expected_ret = manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+    let expected_loads = manycore.direction_loads();
+
+    let csv = "0,2,30\n1,2,20\n2,3,50\n\n3,4,100\n3,5,50\n4,5,30\n";
+    let ret = manycore
+        .route_streaming(csv.as_bytes(), &RoutingAlgorithms::RowFirst)
+        .unwrap();
+
+    assert_eq!(ret, expected_ret);
+    assert_eq!(manycore.direction_loads(), expected_loads);
+
+    let error = manycore
+        .route_streaming("0,2,30\n1,2\n".as_bytes(), &RoutingAlgorithms::RowFirst)
+        .expect_err("Malformed edges should fail");
+    assert!(error.to_string().contains("line 2"));
+}
+
+#[test]
+fn edge_count_multiplies_load() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+    let mut counted = ManycoreSystem::parse_file("tests/EdgeCount.xml")
+        .expect("Could not read input test file \"tests/EdgeCount.xml\"");
+
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+    counted.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    let single = manycore.direction_loads();
+    let tripled = counted.direction_loads();
+
+    // Edge 3 -> 4 alone goes East from core 4.
+    assert_eq!(single[&(4, Directions::East)], 100);
+    assert_eq!(tripled[&(4, Directions::East)], 300);
+    // Core 1 South also carries edges 0 -> 2 and 3 -> 5.
+    assert_eq!(
+        tripled[&(1, Directions::South)],
+        single[&(1, Directions::South)] + 200
+    );
+
+    // Single-send edges do not serialise a count.
+    assert!(!String::try_from(&manycore).unwrap().contains("count"));
+    assert!(String::try_from(&counted).unwrap().contains("count=\"3\""));
+}
+
+#[test]
+fn observed_uses_set_actual_com_cost() {
+    let mut manycore = ManycoreSystem::example_3x3();
+
+    for core in manycore.cores_mut().list_mut() {
+        for channel in core.channels_mut().channel_mut().values_mut() {
+            channel.set_actual_com_cost(0);
+        }
+    }
+
+    manycore.cores_mut().list_mut()[4]
+        .channels_mut()
+        .channel_mut()
+        .get_mut(&Directions::East)
+        .unwrap()
+        .set_actual_com_cost(7);
+
+    manycore.route(&RoutingAlgorithms::Observed).unwrap();
+
+    assert_eq!(
+        manycore.direction_loads(),
+        BTreeMap::from([((4, Directions::East), 7)])
+    );
+}
+
+#[test]
+fn can_route_non_square_meshes() {
+    let mut wide = ManycoreSystem::parse_file("tests/NonSquare2x4.xml")
+        .expect("Could not read input test file \"tests/NonSquare2x4.xml\"");
+
+    wide.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    // 0 -> 4 -> 5 -> 6 -> 7 and 3 -> 7 -> 6 -> 5 -> 4
+    assert_eq!(
+        wide.direction_loads(),
+        BTreeMap::from([
+            ((0, Directions::South), 10),
+            ((3, Directions::South), 20),
+            ((4, Directions::East), 10),
+            ((5, Directions::West), 20),
+            ((5, Directions::East), 10),
+            ((6, Directions::West), 20),
+            ((6, Directions::East), 10),
+            ((7, Directions::West), 20),
+        ])
+    );
+
+    let mut tall = ManycoreSystem::parse_file("tests/NonSquare4x2.xml")
+        .expect("Could not read input test file \"tests/NonSquare4x2.xml\"");
+
+    tall.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    // 0 -> 2 -> 4 -> 6 -> 7 and 1 -> 3 -> 5 -> 7 -> 6
+    assert_eq!(
+        tall.direction_loads(),
+        BTreeMap::from([
+            ((0, Directions::South), 10),
+            ((1, Directions::South), 20),
+            ((2, Directions::South), 10),
+            ((3, Directions::South), 20),
+            ((4, Directions::South), 10),
+            ((5, Directions::South), 20),
+            ((6, Directions::East), 10),
+            ((7, Directions::West), 20),
+        ])
+    );
+}
+
+#[test]
+fn can_route_linear_array() {
+    let mut manycore = ManycoreSystem::parse_file("tests/LinearArray.xml")
+        .expect("Could not read input test file \"tests/LinearArray.xml\"");
+
+    assert_eq!(
+        manycore.cores().list()[0].matrix_edge(),
+        &Some(EdgePosition::TopBottomLeft)
+    );
+    assert_eq!(
+        manycore.cores().list()[3].matrix_edge(),
+        &Some(EdgePosition::TopBottom)
+    );
+    assert_eq!(
+        manycore.cores().list()[7].matrix_edge(),
+        &Some(EdgePosition::TopBottomRight)
+    );
+    // Cores have no neighbours North or South.
+    assert!(manycore.verify_full_mesh().is_ok());
+
+    let expected = (0..7)
+        .map(|id| ((id, Directions::East), 10))
+        .collect::<BTreeMap<(u16, Directions), u16>>();
+
+    for algorithm in [RoutingAlgorithms::RowFirst, RoutingAlgorithms::ColumnFirst] {
+        manycore.route(&algorithm).unwrap();
+
+        assert_eq!(manycore.direction_loads(), expected);
+    }
+}
+
+#[test]
+fn can_route_single_column() {
+    let mut manycore = ManycoreSystem::parse_file("tests/SingleColumn.xml")
+        .expect("Could not read input test file \"tests/SingleColumn.xml\"");
+
+    assert_eq!(
+        manycore.cores().list()[0].matrix_edge(),
+        &Some(EdgePosition::TopLeftRight)
+    );
+    assert_eq!(
+        manycore.cores().list()[1].matrix_edge(),
+        &Some(EdgePosition::LeftRight)
+    );
+    assert_eq!(
+        manycore.cores().list()[2].matrix_edge(),
+        &Some(EdgePosition::BottomLeftRight)
+    );
+    // Cores have no neighbours East or West.
+    assert!(manycore.verify_full_mesh().is_ok());
+
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    // Source on core 1 East, 1 -> 0, 0 -> 1 -> 2, 2 -> 1 and sink on core 1 West.
+    assert_eq!(
+        manycore.direction_loads(),
+        BTreeMap::from([
+            ((0, Directions::South), 20),
+            ((1, Directions::North), 10),
+            ((1, Directions::South), 20),
+            ((1, Directions::West), 30),
+            ((2, Directions::North), 30),
+        ])
+    );
+}
+
+#[test]
+fn can_route_single_core() {
+    let mut manycore = ManycoreSystem::parse_file("tests/SingleCore.xml")
+        .expect("Could not read input test file \"tests/SingleCore.xml\"");
+
+    assert_eq!(
+        manycore.cores().list()[0].matrix_edge(),
+        &Some(EdgePosition::TopBottomLeftRight)
+    );
+    assert!(manycore.verify_full_mesh().is_ok());
+
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    // The source enters from the East, the sink leaves to the South.
+    assert_eq!(
+        manycore.direction_loads(),
+        BTreeMap::from([((0, Directions::South), 20)])
+    );
+}
+
+#[test]
+fn core_traffic_matrix_is_correct() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let matrix = manycore.core_traffic_matrix();
+
+    // Edge 3 -> 4 goes from core 1 to core 5.
+    assert_eq!(matrix.get(&(1, 5)), Some(&100));
+    // Edges 0 -> 2 and 1 -> 2 start from sources on cores 1 and 0, ending on core 7.
+    assert_eq!(matrix.get(&(1, 7)), Some(&30));
+    assert_eq!(matrix.get(&(0, 7)), Some(&20));
+    assert_eq!(matrix.values().sum::<u32>(), 280);
+}
+
+#[test]
+fn can_route_past_256_cores() {
+    let mut manycore = ManycoreSystem::parse_file("tests/Grid20x20.xml")
+        .expect("Could not read input test file \"tests/Grid20x20.xml\"");
+
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    // Edge 0 -> 1 runs from core 0 down to core 380, then right to core 399.
+    // Edge 2 -> 3 runs from core 255 down to core 315, then left to core 300.
+    let mut expected = BTreeMap::new();
+    for row in 0..19 {
+        expected.insert((row * 20, Directions::South), 10);
+    }
+    for column in 0..19 {
+        expected.insert((380 + column, Directions::East), 10);
+    }
+    for id in [255, 275, 295] {
+        expected.insert((id, Directions::South), 20);
+    }
+    for id in 301..=315 {
+        expected.insert((id, Directions::West), 20);
+    }
+
+    assert_eq!(manycore.direction_loads(), expected);
+}
+
+#[test]
+fn west_first_takes_no_forbidden_turns() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let contributions = manycore
+        .route_attributed(&RoutingAlgorithms::WestFirst)
+        .unwrap();
+
+    // Westbound hops must all happen on the start row, before any other hop.
+    // Edge 3 -> 5 leaves core 1 West, then goes South to the sink on core 6.
+    // Edge 4 -> 5 leaves core 5 West twice, then goes South.
+    let mut west = contributions
+        .iter()
+        .filter(|((_, direction), _)| *direction == Directions::West)
+        .map(|((core_id, _), edges)| (*core_id, edges.clone()))
+        .collect::<Vec<_>>();
+    west.sort();
+
+    assert_eq!(
+        west,
+        vec![
+            (1, vec![((3, 5), 50)]),
+            (4, vec![((4, 5), 30)]),
+            (5, vec![((4, 5), 30)]),
+            (6, vec![((3, 5), 50), ((4, 5), 30)]),
+        ]
+    );
+
+    assert!(manycore.route(&RoutingAlgorithms::WestFirst).is_ok());
+}
+
+#[test]
+fn negative_first_is_correct() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    manycore.route(&RoutingAlgorithms::NegativeFirst).unwrap();
+
+    // 3 -> 5 (core 1 to the sink on core 6) goes West before South: 1 -> 0 -> 3 -> 6.
+    // 4 -> 5 (core 5 to the sink on core 6) goes West before South: 5 -> 4 -> 3 -> 6.
+    // 1 -> 2 (core 0 to core 7) breaks South/East ties towards South: 0 -> 3 -> 6 -> 7.
+    // 3 -> 4 (core 1 to core 5) goes East as core 1 South is loaded by 0 -> 2: 1 -> 2 -> 5.
+    let expected = BTreeMap::from([
+        ((0, Directions::South), 70),
+        ((1, Directions::South), 30),
+        ((1, Directions::West), 50),
+        ((1, Directions::East), 100),
+        ((2, Directions::South), 100),
+        ((3, Directions::South), 100),
+        ((4, Directions::North), 50),
+        ((4, Directions::South), 30),
+        ((4, Directions::West), 30),
+        ((5, Directions::West), 30),
+        ((6, Directions::West), 80),
+        ((6, Directions::East), 20),
+        ((7, Directions::North), 50),
+    ]);
+
+    assert_eq!(manycore.direction_loads(), expected);
+}
+
+#[test]
+fn north_last_is_correct() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    manycore.route(&RoutingAlgorithms::NorthLast).unwrap();
+
+    // Same as NegativeFirst, except for 4 -> 5 (core 5 to the sink on core 6), which
+    // breaks the South/West tie towards South: 5 -> 8 -> 7 -> 6.
+    let expected = BTreeMap::from([
+        ((0, Directions::South), 70),
+        ((1, Directions::South), 30),
+        ((1, Directions::West), 50),
+        ((1, Directions::East), 100),
+        ((2, Directions::South), 100),
+        ((3, Directions::South), 70),
+        ((4, Directions::North), 50),
+        ((4, Directions::South), 30),
+        ((5, Directions::South), 30),
+        ((6, Directions::West), 80),
+        ((6, Directions::East), 20),
+        ((7, Directions::North), 50),
+        ((7, Directions::West), 30),
+        ((8, Directions::West), 30),
+    ]);
+
+    assert_eq!(manycore.direction_loads(), expected);
+}
+
+#[test]
+fn minimal_adaptive_avoids_loaded_channel() {
+    let mut manycore = ManycoreSystem::parse_file("tests/MinimalAdaptive.xml")
+        .expect("Could not read input test file \"tests/MinimalAdaptive.xml\"");
+
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    // Edge 0 -> 1 loads core 0 South. RowFirst still routes 0 -> 2 through it: 0 -> 2 -> 3.
+    let expected = BTreeMap::from([((0, Directions::South), 30), ((2, Directions::East), 20)]);
+    assert_eq!(manycore.direction_loads(), expected);
+
+    manycore.route(&RoutingAlgorithms::MinimalAdaptive).unwrap();
+
+    // Both paths to core 3 are minimal, MinimalAdaptive picks the idle East channel: 0 -> 1 -> 3.
+    let expected = BTreeMap::from([
+        ((0, Directions::South), 10),
+        ((0, Directions::East), 20),
+        ((1, Directions::South), 20),
+    ]);
+    assert_eq!(manycore.direction_loads(), expected);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn can_route_and_export() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let (xml, json) = manycore.route_and_export("RowFirst").unwrap();
+
+    assert!(xml.contains("currentLoad=\"180\""));
+
+    let routing: serde_json::Value = serde_json::from_str(&json).expect("Invalid JSON");
+    assert_eq!(routing["1"]["OutputChannel"], serde_json::json!(["South"]));
+    assert_eq!(routing["1"]["SourceChannel"], serde_json::json!(["North"]));
+
+    assert!(manycore.route_and_export("DiagonalFirst").is_err());
+}
+
+#[test]
+fn route_paths_are_correct() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let paths = manycore.route_paths(&RoutingAlgorithms::RowFirst).unwrap();
+
+    assert_eq!(paths.len(), manycore.task_graph().edges().len());
+
+    // Edge 4 -> 5 goes from core 5 to the sink connected West of core 6.
+    let (edge, hops) = &paths[5];
+    assert_eq!((*edge.from(), *edge.to()), (4, 5));
+    assert_eq!(
+        hops,
+        &vec![
+            (5, Directions::South),
+            (8, Directions::West),
+            (7, Directions::West),
+            (6, Directions::West),
+        ]
+    );
+
+    // Loads match plain routing.
+    let loads = manycore.direction_loads();
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+    assert_eq!(loads, manycore.direction_loads());
+
+    assert!(manycore.route_paths(&RoutingAlgorithms::Observed).is_err());
+}
+
+#[test]
+fn simulate_route_does_not_mutate() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let shared = &manycore;
+    let simulated = shared.simulate_route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    assert!(shared.direction_loads().is_empty());
+    assert_eq!(
+        shared.simulate_route(&RoutingAlgorithms::RowFirst).unwrap(),
+        simulated
+    );
+
+    assert_eq!(
+        manycore.route(&RoutingAlgorithms::RowFirst).unwrap(),
+        simulated
+    );
+}
+
+#[test]
+fn route_dry_matches_route() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    for algorithm in [
+        RoutingAlgorithms::RowFirst,
+        RoutingAlgorithms::MinimalAdaptive,
+    ] {
+        let dry = manycore.route_dry(&algorithm).unwrap();
+        assert!(manycore.direction_loads().is_empty());
+
+        manycore.route(&algorithm).unwrap();
+        assert_eq!(dry, manycore.direction_loads().into_iter().collect());
+
+        manycore.clear_channels();
+    }
+}
+
+#[test]
+fn unroutable_edge_is_reported() {
+    // Core 4 has no East channel.
+    let mut manycore = ManycoreSystem::parse_file("tests/MissingChannel.xml")
+        .expect("Could not read input test file \"tests/MissingChannel.xml\"");
+
+    let error = manycore
+        .route(&RoutingAlgorithms::RowFirst)
+        .expect_err("Routing through a missing channel should fail");
+
+    assert_eq!(
+        error.to_string(),
+        "Routing Error: Could not route edge 3 -> 4: core 4 has no East channel."
+    );
+}
+
+#[test]
+fn hop_bound_is_enforced() {
+    let mut manycore = ManycoreSystem::parse_file("tests/Grid20x20.xml")
+        .expect("Could not read input test file \"tests/Grid20x20.xml\"");
+
+    // Parsing rejects inconsistent dimensions, so corrupt them afterwards. The 38 hops
+    // edge 0 -> 1 takes from core 0 to core 399 now exceed the 1 + 20 hops bound.
+    manycore.rows = 1;
+
+    let error = manycore
+        .route(&RoutingAlgorithms::RowFirst)
+        .expect_err("Routing an inconsistent system should fail");
+
+    assert_eq!(
+        error.to_string(),
+        "Routing Error: Could not route edge 0 -> 1: core 399 was not reached within 21 hops."
+    );
+}
+
+#[test]
+fn routing_summary_is_correct() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let summary = manycore.routing_summary();
+    assert_eq!(*summary.total_network_load(), 0);
+    assert_eq!(*summary.peak_channel(), None);
+
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    let summary = manycore.routing_summary();
+    assert_eq!(*summary.total_network_load(), 740);
+    // Sources feed edges 0 -> 2 (30) and 1 -> 2 (20).
+    assert_eq!(*summary.total_source_load(), 50);
+    assert_eq!(*summary.peak_channel(), Some((1, Directions::South, 180)));
+    assert_eq!(
+        summary.per_core_load(),
+        &BTreeMap::from([
+            (0, 20),
+            (1, 180),
+            (2, 0),
+            (3, 20),
+            (4, 230),
+            (5, 30),
+            (6, 100),
+            (7, 130),
+            (8, 30),
+        ])
+    );
+}
+
+#[test]
+fn can_route_checks_preconditions() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert!(manycore.can_route(&RoutingAlgorithms::RowFirst).is_ok());
+    assert!(manycore.can_route(&RoutingAlgorithms::Observed).is_ok());
+
+    let unobserved = ManycoreSystem::parse_file("tests/NoRoutingAlgo.xml")
+        .expect("Could not read input test file \"tests/NoRoutingAlgo.xml\"");
+
+    assert_eq!(
+        unobserved
+            .can_route(&RoutingAlgorithms::Observed)
+            .unwrap_err()
+            .to_string(),
+        "Routing Error: Cannot mirror observed routing: the system does not specify a routingAlgo."
+    );
+    assert!(unobserved.can_route(&RoutingAlgorithms::RowFirst).is_ok());
+
+    let faulted = ManycoreSystem::parse_file("tests/MissingChannel.xml")
+        .expect("Could not read input test file \"tests/MissingChannel.xml\"");
+
+    assert!(faulted.can_route(&RoutingAlgorithms::RowFirst).is_err());
+}
@@ -0,0 +1,35 @@
+#[cfg(test)]
+use crate::{ManycoreSystem, RoutingAlgorithms};
+
+#[test]
+fn can_reset() {
+    let mut manycore = ManycoreSystem::parse_file_with_snapshot("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+    let fresh = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    // Move a task and route.
+    let core = manycore.task_core_map[&3];
+    manycore.task_core_map.insert(4, core);
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    assert_ne!(manycore, fresh);
+
+    manycore.reset().expect("Could not reset system");
+
+    assert_eq!(manycore, fresh);
+
+    // The snapshot survives a reset.
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+    manycore.reset().expect("Could not reset system");
+
+    assert_eq!(manycore, fresh);
+}
+
+#[test]
+fn reset_requires_snapshot() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert!(manycore.reset().is_err());
+}
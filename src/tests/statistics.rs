@@ -0,0 +1,212 @@
+#[cfg(test)]
+use std::collections::BTreeMap;
+
+#[cfg(test)]
+use crate::{Directions, ManycoreSystem, RoutingAlgorithms};
+
+#[test]
+fn direction_loads_match_row_first() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    let expected = BTreeMap::from([
+        ((0, Directions::South), 20),
+        ((1, Directions::South), 180),
+        ((3, Directions::South), 20),
+        ((4, Directions::North), 50),
+        ((4, Directions::South), 80),
+        ((4, Directions::East), 100),
+        ((5, Directions::South), 30),
+        ((6, Directions::West), 80),
+        ((6, Directions::East), 20),
+        ((7, Directions::North), 50),
+        ((7, Directions::West), 80),
+        ((8, Directions::West), 30),
+    ]);
+
+    assert_eq!(manycore.direction_loads(), expected);
+}
+
+#[test]
+fn core_utilisation_is_correct() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    let utilisation = manycore.core_utilisation();
+
+    assert_eq!(utilisation.len(), 9);
+    // Core 4 sends 50 North, 80 South and 100 East over 4 * 400 bandwidth.
+    assert_eq!(utilisation.get(&4), Some(&(230.0 / 1600.0)));
+    assert_eq!(utilisation.get(&2), Some(&0.0));
+}
+
+#[test]
+fn routing_csv_matches_fixture() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    let expected = std::fs::read_to_string("tests/RowFirstRouting.csv")
+        .expect("Could not read expected CSV file \"tests/RowFirstRouting.csv\"");
+
+    assert_eq!(manycore.routing_csv(), expected);
+}
+
+#[test]
+fn load_variance_differs_between_algorithms() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert_eq!(manycore.load_variance(), 0.0);
+
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+    let row_first = manycore.load_variance();
+
+    manycore.route(&RoutingAlgorithms::ColumnFirst).unwrap();
+    let column_first = manycore.load_variance();
+
+    assert!(row_first > 0.0);
+    assert_ne!(row_first, column_first);
+}
+
+#[test]
+fn attributes_table_is_correct() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let (header, rows) = manycore.attributes_table();
+
+    for key in ["@actualFrequency", "@age", "@status", "@temperature"] {
+        assert!(header.contains(&key.to_string()));
+    }
+    assert_eq!(rows.len(), manycore.cores().list().len());
+
+    let age = header.iter().position(|key| key == "@age").unwrap();
+    assert_eq!(rows[1][age], Some("394".to_string()));
+}
+
+#[test]
+fn distance_matrix_is_correct() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let distances = manycore.distance_matrix();
+
+    assert_eq!(distances.len(), 9);
+    assert_eq!(distances[0][8], 4);
+    assert_eq!(distances[8][0], 4);
+    assert_eq!(distances[4][4], 0);
+    assert_eq!(distances[1][3], 2);
+}
+
+#[test]
+fn energy_estimate_is_correct() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert_eq!(manycore.energy_estimate(0.5, 1.5), 0.0);
+
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    // 740 units of channel load plus 50 units injected by sources.
+    assert_eq!(manycore.energy_estimate(0.5, 1.5), 1580.0);
+}
+
+#[test]
+fn channels_above_threshold_are_correct() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert!(manycore.channels_above(0.1).is_empty());
+
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    assert_eq!(
+        manycore.channels_above(0.1),
+        vec![
+            (1, Directions::South, 0.45),
+            (4, Directions::North, 0.125),
+            (4, Directions::South, 0.2),
+            (4, Directions::East, 0.25),
+            (6, Directions::West, 0.2),
+            (7, Directions::North, 0.125),
+            (7, Directions::West, 0.2),
+        ]
+    );
+}
+
+#[test]
+fn minimum_total_load_bounds_row_first() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    // Task 3 sits on core 1 and task 2 on core 7.
+    assert_eq!(manycore.task_distance(3, 2).unwrap(), 2);
+
+    let minimum = manycore.minimum_total_load().unwrap();
+
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+    let row_first = manycore
+        .direction_loads()
+        .values()
+        .map(|load| u64::from(*load))
+        .sum::<u64>();
+
+    assert!(minimum > 0);
+    assert!(row_first >= minimum);
+}
+
+#[test]
+fn direction_asymmetry_is_correct() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert!(manycore
+        .direction_asymmetry()
+        .values()
+        .all(|ratio| *ratio == 1.0));
+
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    assert_eq!(
+        manycore.load_by_direction(),
+        BTreeMap::from([
+            (Directions::North, 100),
+            (Directions::South, 330),
+            (Directions::West, 190),
+            (Directions::East, 120),
+        ])
+    );
+
+    // 740 units over 4 directions, a mean of 185.
+    let asymmetry = manycore.direction_asymmetry();
+    assert_eq!(asymmetry[&Directions::North], 100.0 / 185.0);
+    assert_eq!(asymmetry[&Directions::South], 330.0 / 185.0);
+    assert!(asymmetry.values().any(|ratio| *ratio != 1.0));
+}
+
+#[test]
+fn overloaded_channels_are_correct() {
+    let mut manycore = ManycoreSystem::parse_file("tests/HeavyWorkload.xml")
+        .expect("Could not read input test file \"tests/HeavyWorkload.xml\"");
+
+    assert!(manycore.overloaded_channels().is_empty());
+
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+
+    // 0 -> 3 (300) goes 0 -> 2 -> 3, 1 -> 2 (250) goes 1 -> 3 -> 2. Every bandwidth is 200.
+    assert_eq!(
+        manycore.overloaded_channels(),
+        vec![
+            (0, Directions::South, 300, 200),
+            (1, Directions::South, 250, 200),
+            (2, Directions::East, 300, 200),
+            (3, Directions::West, 250, 200),
+        ]
+    );
+}
@@ -0,0 +1,111 @@
+#[cfg(test)]
+use std::{collections::HashMap, fs::read_to_string};
+
+#[cfg(test)]
+use crate::{Directions, ManycoreSystem, SinkSourceDirection};
+
+#[test]
+fn detects_inconsistent_channel_attributes() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert!(manycore.inconsistent_channel_attributes().is_empty());
+
+    let manycore = ManycoreSystem::parse_file("tests/InconsistentChannels.xml")
+        .expect("Could not read input test file \"tests/InconsistentChannels.xml\"");
+
+    assert_eq!(
+        manycore.inconsistent_channel_attributes(),
+        vec![(4, Directions::North, "@age".to_string())]
+    );
+}
+
+#[test]
+fn detects_swapped_dimensions() {
+    let error = ManycoreSystem::parse_file("tests/SwappedDimensions.xml")
+        .expect_err("Parsing swapped dimensions should fail");
+
+    assert!(error
+        .to_string()
+        .contains("rows and columns might be swapped"));
+}
+
+#[test]
+fn verifies_full_mesh() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert!(manycore.verify_full_mesh().is_ok());
+
+    // Outward channels are optional: every edge core above has them, dropping them is fine too.
+    let mut manycore = manycore;
+    for core in manycore.cores_mut().list_mut() {
+        let outward = core
+            .matrix_edge()
+            .as_ref()
+            .map(Vec::<SinkSourceDirection>::from)
+            .unwrap_or_default();
+
+        for direction in outward {
+            core.channels_mut()
+                .channel_mut()
+                .remove(&Directions::from(&direction));
+        }
+    }
+    assert_eq!(manycore.cores().list()[0].channels().channel().len(), 2);
+    assert!(manycore.verify_full_mesh().is_ok());
+
+    let manycore = ManycoreSystem::parse_file("tests/MissingChannel.xml")
+        .expect("Could not read input test file \"tests/MissingChannel.xml\"");
+
+    let error = manycore
+        .verify_full_mesh()
+        .expect_err("Core 4 has no East channel");
+
+    assert!(error
+        .to_string()
+        .contains("Core 4 is missing its East channel"));
+}
+
+#[test]
+fn validates_attribute_ranges() {
+    let ranges = HashMap::from([("@temperature".to_string(), (0.0, 150.0))]);
+
+    assert!(ManycoreSystem::parse_file_with_ranges("tests/VisualiserOutput1.xml", &ranges).is_ok());
+
+    let error = ManycoreSystem::parse_file_with_ranges("tests/OutOfRangeTemperature.xml", &ranges)
+        .expect_err("Out of range temperature should fail");
+
+    assert_eq!(
+        error.to_string(),
+        "Generation Error: Core 0 has @temperature = 200, outside of the declared range 0..150."
+    );
+
+    let content = read_to_string("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    // Negative and fractional values are not classified as numbers, but are still checked.
+    for value in ["-5", "150.5"] {
+        let xml = content.replace("temperature=\"45\"", &format!("temperature=\"{value}\""));
+
+        let error = ManycoreSystem::parse_str(&xml)
+            .expect("Could not parse edited \"tests/VisualiserOutput1.xml\"")
+            .check_attribute_ranges(&ranges)
+            .expect_err("Out of range temperature should fail");
+
+        assert_eq!(
+            error.to_string(),
+            format!("Generation Error: Core 0 has @temperature = {value}, outside of the declared range 0..150.")
+        );
+    }
+
+    let status_ranges = HashMap::from([("@status".to_string(), (0.0, 1.0))]);
+    let error =
+        ManycoreSystem::parse_file_with_ranges("tests/VisualiserOutput1.xml", &status_ranges)
+            .expect_err("Non numeric ranged attribute should fail");
+
+    assert_eq!(
+        error.to_string(),
+        "Generation Error: Core 0 has @status = High, which is not a number but has a declared range."
+    );
+}
@@ -1 +1,3 @@
 pub mod attrs;
+pub mod borders;
+pub mod task_id;
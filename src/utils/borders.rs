@@ -0,0 +1,47 @@
+use std::collections::BTreeMap;
+
+use manycore_utils::BTreeVector;
+use serde::{de::Error, Deserialize, Deserializer};
+
+use crate::{Sink, Source, TaskIDT};
+
+/// Deserialises a sequence of border elements into a map keyed by task id.
+/// Unlike [`manycore_utils::deserialize_btree_vector`], a task id appearing twice
+/// is an error rather than silently overwriting the earlier element.
+fn deserialize_unique_tasks<'de, D, T>(
+    deserializer: D,
+    element: &str,
+) -> Result<BTreeMap<TaskIDT, T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + BTreeVector<TaskIDT>,
+{
+    let elements: Vec<T> = Deserialize::deserialize(deserializer)?;
+    let mut ret = BTreeMap::new();
+
+    for border in elements {
+        let task_id = border.key();
+
+        if ret.insert(task_id, border).is_some() {
+            return Err(D::Error::custom(format!(
+                "Task {task_id} is allocated on more than one {element}."
+            )));
+        }
+    }
+
+    Ok(ret)
+}
+
+/// Utility function to deserialise `<Source>` elements, rejecting duplicated task ids.
+pub(crate) fn deserialize_sources<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<BTreeMap<TaskIDT, Source>, D::Error> {
+    deserialize_unique_tasks(deserializer, "source")
+}
+
+/// Utility function to deserialise `<Sink>` elements, rejecting duplicated task ids.
+pub(crate) fn deserialize_sinks<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<BTreeMap<TaskIDT, Sink>, D::Error> {
+    deserialize_unique_tasks(deserializer, "sink")
+}
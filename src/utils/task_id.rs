@@ -0,0 +1,82 @@
+use std::fmt;
+
+use serde::{
+    de::{Error, Visitor},
+    Deserializer,
+};
+
+use crate::TaskIDT;
+
+/// Parses a task id that is either a bare number ("3") or a number with an
+/// alphabetic prefix ("t3"), as produced by legacy exporters.
+/// Signed values ("-3", "+3") are rejected.
+fn parse_task_id(value: &str) -> Option<TaskIDT> {
+    let digits = value.trim_start_matches(|c: char| c.is_ascii_alphabetic());
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    digits.parse::<TaskIDT>().ok()
+}
+
+/// Visitor accepting task ids as numbers or (optionally prefixed) strings.
+/// Numbers are produced by self-describing binary formats, strings by XML.
+struct TaskIDVisitor;
+
+impl<'de> Visitor<'de> for TaskIDVisitor {
+    type Value = TaskIDT;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a task id, optionally prefixed (e.g. 3 or \"t3\")")
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        TaskIDT::try_from(v).map_err(|_| E::custom(format!("'{v}' is not a valid task id.")))
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        TaskIDT::try_from(v).map_err(|_| E::custom(format!("'{v}' is not a valid task id.")))
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        parse_task_id(v).ok_or(E::custom(format!("'{v}' is not a valid task id.")))
+    }
+}
+
+/// Visitor for optional task ids.
+struct OptionalTaskIDVisitor;
+
+impl<'de> Visitor<'de> for OptionalTaskIDVisitor {
+    type Value = Option<TaskIDT>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an optional task id")
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserialize_task_id(deserializer).map(Some)
+    }
+}
+
+/// Utility function to deserialise a task id, stripping any alphabetic prefix.
+pub(crate) fn deserialize_task_id<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<TaskIDT, D::Error> {
+    deserializer.deserialize_any(TaskIDVisitor)
+}
+
+/// Same as [`deserialize_task_id`] but for optional task ids.
+pub(crate) fn deserialize_optional_task_id<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<TaskIDT>, D::Error> {
+    deserializer.deserialize_option(OptionalTaskIDVisitor)
+}
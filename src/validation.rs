@@ -0,0 +1,208 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::{
+    generation_error, Core, Directions, ElementIDT, ErrorSubsystem, ManycoreError, ManycoreSystem,
+    WithID, WithXMLAttributes, COORDINATES_KEY,
+};
+
+#[cfg(doc)]
+use crate::EdgePosition;
+
+/// Parses a coordinates attribute value formatted as "x,y", where x is the column and y the row.
+/// Surrounding parentheses and whitespace are tolerated, e.g. "(1, 2)".
+fn parse_coordinates(value: &str) -> Option<(ElementIDT, ElementIDT)> {
+    let (x, y) = value
+        .trim()
+        .trim_start_matches('(')
+        .trim_end_matches(')')
+        .split_once(',')?;
+
+    Some((x.trim().parse().ok()?, y.trim().parse().ok()?))
+}
+
+/// Checks every attribute of the element that has a declared range against it. Values are
+/// parsed as [`f64`], regardless of the type inferred for the attribute.
+/// `description` lazily provides a user friendly name for the element, used in the error message.
+fn check_ranges<T: WithXMLAttributes>(
+    element: &T,
+    description: impl Fn() -> String,
+    ranges: &HashMap<String, (f64, f64)>,
+) -> Result<(), ManycoreError> {
+    let Some(attributes) = element.other_attributes() else {
+        return Ok(());
+    };
+
+    for (key, value) in attributes {
+        if let Some((min, max)) = ranges.get(key) {
+            let number = value.trim().parse::<f64>().map_err(|_| {
+                generation_error(
+                    ErrorSubsystem::Cores,
+                    format!(
+                        "{} has {key} = {value}, which is not a number but has a declared range.",
+                        description()
+                    ),
+                )
+            })?;
+
+            if !(number >= *min && number <= *max) {
+                return Err(generation_error(
+                    ErrorSubsystem::Cores,
+                    format!(
+                        "{} has {key} = {value}, outside of the declared range {min}..{max}.",
+                        description()
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl Core {
+    /// Cross-checks the core's `@coordinates` attribute, if present, against the position its id
+    /// implies in a matrix of the given dimensions. A mismatch that would be resolved by swapping
+    /// rows and columns is reported as such.
+    pub(crate) fn validate_coordinates(
+        &self,
+        columns: ElementIDT,
+        rows: ElementIDT,
+    ) -> Result<(), ManycoreError> {
+        let Some(value) = self
+            .other_attributes()
+            .as_ref()
+            .and_then(|attributes| attributes.get(COORDINATES_KEY))
+        else {
+            return Ok(());
+        };
+
+        let id = *self.id();
+        let coordinates = parse_coordinates(value).ok_or(generation_error(
+            ErrorSubsystem::Dimensions,
+            format!("Core {id} has malformed coordinates '{value}'. Expected format is 'x,y'."),
+        ))?;
+
+        if coordinates == (id % columns, id / columns) {
+            return Ok(());
+        }
+
+        let hint = if coordinates == (id % rows, id / rows) {
+            " Hint: rows and columns might be swapped."
+        } else {
+            ""
+        };
+
+        Err(generation_error(ErrorSubsystem::Dimensions, format!(
+            "Core {id} coordinates '{value}' do not match the declared {rows} rows and {columns} columns.{hint}"
+        )))
+    }
+}
+
+impl ManycoreSystem {
+    /// Parses the provided file like [`ManycoreSystem::parse_file`], then checks every attribute
+    /// with an entry in `ranges` is a number within its inclusive (min, max) bounds.
+    /// Keys must include the `@` prefix, e.g. `@temperature`.
+    pub fn parse_file_with_ranges(
+        path: &str,
+        ranges: &HashMap<String, (f64, f64)>,
+    ) -> Result<ManycoreSystem, ManycoreError> {
+        let manycore = ManycoreSystem::parse_file(path)?;
+        manycore.check_attribute_ranges(ranges)?;
+
+        Ok(manycore)
+    }
+
+    /// Range checks behind [`ManycoreSystem::parse_file_with_ranges`].
+    pub(crate) fn check_attribute_ranges(
+        &self,
+        ranges: &HashMap<String, (f64, f64)>,
+    ) -> Result<(), ManycoreError> {
+        for core in self.cores().list() {
+            let id = core.id();
+
+            check_ranges(core, || format!("Core {id}"), ranges)?;
+            check_ranges(core.router(), || format!("Router {id}"), ranges)?;
+
+            for (direction, channel) in core.channels().channel() {
+                check_ranges(channel, || format!("Core {id} {direction} channel"), ranges)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Lint-style check over channel attributes. Channels are expected to share the same
+    /// attribute keys (values may differ). A key is expected if the majority of channels declare it.
+    /// Returns a list of (core id, channel direction, missing key) for every channel that lacks
+    /// an expected key.
+    pub fn inconsistent_channel_attributes(&self) -> Vec<(ElementIDT, Directions, String)> {
+        let mut key_count: BTreeMap<&String, usize> = BTreeMap::new();
+        let mut total_channels = 0usize;
+
+        for core in self.cores().list() {
+            for channel in core.channels().channel().values() {
+                total_channels += 1;
+
+                if let Some(attributes) = channel.other_attributes() {
+                    for key in attributes.keys() {
+                        *key_count.entry(key).or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+
+        // Keys declared by the majority of channels.
+        let expected_keys = key_count
+            .into_iter()
+            .filter_map(|(key, count)| (count * 2 > total_channels).then_some(key))
+            .collect::<Vec<&String>>();
+
+        let mut ret = Vec::new();
+
+        for core in self.cores().list() {
+            for (direction, channel) in core.channels().channel() {
+                for key in &expected_keys {
+                    let has_key = channel
+                        .other_attributes()
+                        .as_ref()
+                        .is_some_and(|attributes| attributes.contains_key(*key));
+
+                    if !has_key {
+                        ret.push((*core.id(), *direction, (*key).clone()));
+                    }
+                }
+            }
+        }
+
+        ret
+    }
+
+    /// Verifies the mesh connectivity routing relies on: every core must have a channel towards
+    /// each of its neighbours. This is looser than an exact match against the core's
+    /// [`EdgePosition`]: channels pointing off the matrix edge connect to border routers, so they
+    /// are neither required nor rejected, whether or not a border router is attached to them.
+    /// Returns an error describing the first violation, if any.
+    pub fn verify_full_mesh(&self) -> Result<(), ManycoreError> {
+        for core in self.cores().list() {
+            // Outward facing directions, if the core sits on the matrix edge.
+            let outward = core
+                .matrix_edge()
+                .as_ref()
+                .map(BTreeSet::<&Directions>::from)
+                .unwrap_or_default();
+
+            for direction in Directions::all() {
+                if !outward.contains(&direction)
+                    && !core.channels().channel().contains_key(&direction)
+                {
+                    return Err(generation_error(ErrorSubsystem::Cores, format!(
+                        "Core {} is missing its {direction} channel, which is required to reach its neighbour.",
+                        core.id()
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}